@@ -0,0 +1,120 @@
+#ifndef OHM_H
+#define OHM_H
+
+/* Generated by cbindgen from src/ffi.rs; do not edit by hand. */
+
+#include <stdarg.h>
+#include <stdbool.h>
+#include <stdint.h>
+#include <stdlib.h>
+
+/**
+ * Colors of the bands.
+ */
+typedef enum OhmBand {
+  OHM_BAND_BLACK,
+  OHM_BAND_BROWN,
+  OHM_BAND_RED,
+  OHM_BAND_ORANGE,
+  OHM_BAND_YELLOW,
+  OHM_BAND_GREEN,
+  OHM_BAND_BLUE,
+  OHM_BAND_VIOLET,
+  OHM_BAND_GREY,
+  OHM_BAND_WHITE,
+  OHM_BAND_GOLD,
+  OHM_BAND_SILVER,
+  OHM_BAND_PINK,
+} OhmBand;
+
+/**
+ * Outcome of a call to the C API.
+ */
+typedef enum OhmStatus {
+  OHM_STATUS_OK,
+  OHM_STATUS_INVALID_CODE,
+  OHM_STATUS_OUT_OF_RANGE,
+  OHM_STATUS_UNSUPPORTED_BAND,
+  OHM_STATUS_REVERSED_BAND_ORDER,
+  OHM_STATUS_UNREPRESENTABLE,
+  /**
+   * The text is not a resistance or tolerance.
+   */
+  OHM_STATUS_INVALID_VALUE,
+  /**
+   * A required pointer is null.
+   */
+  OHM_STATUS_NULL_POINTER,
+  /**
+   * A band is not one of the [OhmBand] values.
+   */
+  OHM_STATUS_INVALID_BAND,
+} OhmStatus;
+
+/**
+ * A resistance along with the range allowed by its tolerance.
+ */
+typedef struct OhmResistance {
+  /**
+   * Ideal value in Ohms.
+   */
+  double nominal;
+  /**
+   * Lowest value in Ohms allowed by the tolerance.
+   */
+  double min;
+  /**
+   * Highest value in Ohms allowed by the tolerance.
+   */
+  double max;
+  /**
+   * Allowed tolerance as a percentage.
+   */
+  double tolerance;
+  /**
+   * Temperature coefficient in ppm/K, or 0 when `has_tempco` is false.
+   */
+  uint32_t tempco;
+  /**
+   * Whether the temperature coefficient is marked.
+   */
+  bool has_tempco;
+} OhmResistance;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Reads the `len` bands at `bands`, listed from left to right, into `out`.
+ *
+ * # Safety
+ *
+ * `bands` must point to `len` readable values and `out` must point to
+ * writable memory for an [OhmResistance]. Values that are not an [OhmBand]
+ * are rejected with [OhmStatus::InvalidBand].
+ */
+OhmStatus ohm_decode_bands(const uint32_t *bands, uintptr_t len, OhmResistance *out);
+
+/**
+ * Reads the resistance written as `value` (ex: `4k7`) into `out`, with the
+ * `tolerance` (ex: `1%`) or 5% when `tolerance` is null.
+ *
+ * # Safety
+ *
+ * `value` and any `tolerance` must be NUL-terminated strings and `out` must
+ * point to writable memory for an [OhmResistance].
+ */
+OhmStatus ohm_decode_value(const char *value, const char *tolerance, OhmResistance *out);
+
+/**
+ * Describes the `status` as a NUL-terminated string that lives for the
+ * duration of the program.
+ */
+const char *ohm_status_message(OhmStatus status);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* OHM_H */
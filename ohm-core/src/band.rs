@@ -0,0 +1,604 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::standard::Standard;
+use crate::value::Ppm;
+
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Band {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    White,
+    Gold,
+    Silver,
+    Pink,
+}
+
+impl Band {
+    pub fn ascii(&self) -> &'static str {
+        match self {
+            Self::Black => "black",
+            Self::Brown => "brown",
+            Self::Red => "red",
+            Self::Orange => "orange",
+            Self::Yellow => "yellow",
+            Self::Green => "green",
+            Self::Blue => "blue",
+            Self::Violet => "violet",
+            Self::Grey => "grey",
+            Self::White => "white",
+            Self::Gold => "gold",
+            Self::Silver => "silver",
+            Self::Pink => "pink",
+        }
+    }
+
+    /// Returns the two-letter abbreviation of the band's color from IEC 60757.
+    pub fn iec(&self) -> &'static str {
+        match self {
+            Self::Black => "BK",
+            Self::Brown => "BN",
+            Self::Red => "RD",
+            Self::Orange => "OG",
+            Self::Yellow => "YE",
+            Self::Green => "GN",
+            Self::Blue => "BU",
+            Self::Violet => "VT",
+            Self::Grey => "GY",
+            Self::White => "WH",
+            Self::Gold => "GD",
+            Self::Silver => "SR",
+            Self::Pink => "PK",
+        }
+    }
+
+    /// Returns a symbol that is unique to the band's color, so bands can be
+    /// told apart without seeing their colors.
+    pub fn symbol(&self) -> char {
+        match self {
+            Self::Black => '■',
+            Self::Brown => '▲',
+            Self::Red => '●',
+            Self::Orange => '◆',
+            Self::Yellow => '★',
+            Self::Green => '♣',
+            Self::Blue => '♠',
+            Self::Violet => '♥',
+            Self::Grey => '▼',
+            Self::White => '□',
+            Self::Gold => '◎',
+            Self::Silver => '◇',
+            Self::Pink => '✿',
+        }
+    }
+
+    /// Returns the digit of the band's color, or a letter for colors without a
+    /// digit.
+    pub fn code(&self) -> char {
+        match self {
+            Self::Black => '0',
+            Self::Brown => '1',
+            Self::Red => '2',
+            Self::Orange => '3',
+            Self::Yellow => '4',
+            Self::Green => '5',
+            Self::Blue => '6',
+            Self::Violet => '7',
+            Self::Grey => '8',
+            Self::White => '9',
+            Self::Gold => 'G',
+            Self::Silver => 'S',
+            Self::Pink => 'P',
+        }
+    }
+
+    /// Returns the sRGB color used to draw the band.
+    pub fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Self::Black => (0x00, 0x00, 0x00),
+            Self::Brown => (0x96, 0x4B, 0x00),
+            Self::Red => (0xFF, 0x00, 0x00),
+            Self::Orange => (0xFF, 0xA5, 0x00),
+            Self::Yellow => (0xFF, 0xFF, 0x00),
+            Self::Green => (0x00, 0x80, 0x00),
+            Self::Blue => (0x00, 0x00, 0xFF),
+            Self::Violet => (0x8F, 0x00, 0xFF),
+            Self::Grey => (0x80, 0x80, 0x80),
+            Self::White => (0xFF, 0xFF, 0xFF),
+            Self::Gold => (0xFF, 0xD7, 0x00),
+            Self::Silver => (0xAA, 0xA9, 0xAD),
+            Self::Pink => (0xFF, 0x69, 0xB4),
+        }
+    }
+}
+
+pub trait FromBand {
+    type Err;
+
+    fn from_band(b: &Band) -> Result<Self, Self::Err>
+    where
+        Self: Sized;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Digit {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    White,
+}
+
+impl FromBand for Digit {
+    type Err = BandError;
+
+    fn from_band(b: &Band) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Ok(match b {
+            Band::Black => Self::Black,
+            Band::Brown => Self::Brown,
+            Band::Red => Self::Red,
+            Band::Orange => Self::Orange,
+            Band::Yellow => Self::Yellow,
+            Band::Green => Self::Green,
+            Band::Blue => Self::Blue,
+            Band::Violet => Self::Violet,
+            Band::Grey => Self::Grey,
+            Band::White => Self::White,
+            _ => return Err(BandError::UnsupportedBand("digit", b.clone())),
+        })
+    }
+}
+
+impl From<Digit> for Band {
+    fn from(d: Digit) -> Self {
+        match d {
+            Digit::Black => Self::Black,
+            Digit::Brown => Self::Brown,
+            Digit::Red => Self::Red,
+            Digit::Orange => Self::Orange,
+            Digit::Yellow => Self::Yellow,
+            Digit::Green => Self::Green,
+            Digit::Blue => Self::Blue,
+            Digit::Violet => Self::Violet,
+            Digit::Grey => Self::Grey,
+            Digit::White => Self::White,
+        }
+    }
+}
+
+impl TryFrom<u8> for Digit {
+    type Error = BandError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Black,
+            1 => Self::Brown,
+            2 => Self::Red,
+            3 => Self::Orange,
+            4 => Self::Yellow,
+            5 => Self::Green,
+            6 => Self::Blue,
+            7 => Self::Violet,
+            8 => Self::Grey,
+            9 => Self::White,
+            _ => return Err(BandError::Unrepresentable(Quantity::Digit(value))),
+        })
+    }
+}
+
+impl From<Digit> for u8 {
+    fn from(d: Digit) -> Self {
+        match d {
+            Digit::Black => 0,
+            Digit::Brown => 1,
+            Digit::Red => 2,
+            Digit::Orange => 3,
+            Digit::Yellow => 4,
+            Digit::Green => 5,
+            Digit::Blue => 6,
+            Digit::Violet => 7,
+            Digit::Grey => 8,
+            Digit::White => 9,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Multiplier {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    White,
+    Gold,
+    Silver,
+    Pink,
+}
+
+impl FromBand for Multiplier {
+    type Err = BandError;
+
+    fn from_band(b: &Band) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Ok(match b {
+            Band::Black => Self::Black,
+            Band::Brown => Self::Brown,
+            Band::Red => Self::Red,
+            Band::Orange => Self::Orange,
+            Band::Yellow => Self::Yellow,
+            Band::Green => Self::Green,
+            Band::Blue => Self::Blue,
+            Band::Violet => Self::Violet,
+            Band::Grey => Self::Grey,
+            Band::White => Self::White,
+            Band::Gold => Self::Gold,
+            Band::Silver => Self::Silver,
+            Band::Pink => Self::Pink,
+        })
+    }
+}
+
+impl From<Multiplier> for Band {
+    fn from(m: Multiplier) -> Self {
+        match m {
+            Multiplier::Black => Self::Black,
+            Multiplier::Brown => Self::Brown,
+            Multiplier::Red => Self::Red,
+            Multiplier::Orange => Self::Orange,
+            Multiplier::Yellow => Self::Yellow,
+            Multiplier::Green => Self::Green,
+            Multiplier::Blue => Self::Blue,
+            Multiplier::Violet => Self::Violet,
+            Multiplier::Grey => Self::Grey,
+            Multiplier::White => Self::White,
+            Multiplier::Gold => Self::Gold,
+            Multiplier::Silver => Self::Silver,
+            Multiplier::Pink => Self::Pink,
+        }
+    }
+}
+
+impl TryFrom<i8> for Multiplier {
+    type Error = BandError;
+
+    fn try_from(value: i8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::Black,
+            1 => Self::Brown,
+            2 => Self::Red,
+            3 => Self::Orange,
+            4 => Self::Yellow,
+            5 => Self::Green,
+            6 => Self::Blue,
+            7 => Self::Violet,
+            8 => Self::Grey,
+            9 => Self::White,
+            -1 => Self::Gold,
+            -2 => Self::Silver,
+            -3 => Self::Pink,
+            _ => return Err(BandError::Unrepresentable(Quantity::Multiplier(value))),
+        })
+    }
+}
+
+impl From<Multiplier> for i8 {
+    fn from(m: Multiplier) -> Self {
+        match m {
+            Multiplier::Black => 0,
+            Multiplier::Brown => 1,
+            Multiplier::Red => 2,
+            Multiplier::Orange => 3,
+            Multiplier::Yellow => 4,
+            Multiplier::Green => 5,
+            Multiplier::Blue => 6,
+            Multiplier::Violet => 7,
+            Multiplier::Grey => 8,
+            Multiplier::White => 9,
+            Multiplier::Gold => -1,
+            Multiplier::Silver => -2,
+            Multiplier::Pink => -3,
+        }
+    }
+}
+
+/// Serialized as the name of its band, or null for the default tolerance.
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(into = "Option<Band>", try_from = "Option<Band>")
+)]
+pub enum Tolerance {
+    // Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    // White,
+    Gold,
+    Silver,
+    /// Default tolerance for a 3-band resistor.
+    Default,
+}
+
+impl FromBand for Tolerance {
+    type Err = BandError;
+
+    fn from_band(b: &Band) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Ok(match b {
+            Band::Brown => Self::Brown,
+            Band::Red => Self::Red,
+            Band::Orange => Self::Orange,
+            Band::Yellow => Self::Yellow,
+            Band::Green => Self::Green,
+            Band::Blue => Self::Blue,
+            Band::Violet => Self::Violet,
+            Band::Grey => Self::Grey,
+            Band::Gold => Self::Gold,
+            Band::Silver => Self::Silver,
+            _ => return Err(BandError::UnsupportedBand("tolerance", b.clone())),
+        })
+    }
+}
+
+impl Tolerance {
+    /// Every tolerance that is marked by a band, in order.
+    pub const ALL: [Self; 10] = [
+        Self::Brown,
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Green,
+        Self::Blue,
+        Self::Violet,
+        Self::Grey,
+        Self::Gold,
+        Self::Silver,
+    ];
+
+    /// Returns the band that marks the tolerance, if any.
+    pub fn band(&self) -> Option<Band> {
+        match self {
+            Self::Brown => Some(Band::Brown),
+            Self::Red => Some(Band::Red),
+            Self::Orange => Some(Band::Orange),
+            Self::Yellow => Some(Band::Yellow),
+            Self::Green => Some(Band::Green),
+            Self::Blue => Some(Band::Blue),
+            Self::Violet => Some(Band::Violet),
+            Self::Grey => Some(Band::Grey),
+            Self::Gold => Some(Band::Gold),
+            Self::Silver => Some(Band::Silver),
+            Self::Default => None,
+        }
+    }
+}
+
+impl From<Tolerance> for Option<Band> {
+    fn from(t: Tolerance) -> Self {
+        t.band()
+    }
+}
+
+impl TryFrom<Option<Band>> for Tolerance {
+    type Error = BandError;
+
+    /// Reads the band that marks the tolerance, or the default tolerance
+    /// when there is none.
+    fn try_from(value: Option<Band>) -> Result<Self, Self::Error> {
+        value.map_or(Ok(Self::Default), |b| Self::from_band(&b))
+    }
+}
+
+impl TryFrom<Ppm> for Tolerance {
+    type Error = BandError;
+
+    /// Finds the tolerance band under the classic standard.
+    fn try_from(value: Ppm) -> Result<Self, Self::Error> {
+        Standard::CLASSIC.tolerance_band(value)
+    }
+}
+
+impl From<Tolerance> for Ppm {
+    /// Returns the tolerance under the classic standard.
+    fn from(t: Tolerance) -> Self {
+        Standard::CLASSIC.tolerance(t).unwrap()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum TempCoeff {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    // White,
+    // Gold,
+    // Silver
+}
+
+impl FromBand for TempCoeff {
+    type Err = BandError;
+
+    fn from_band(b: &Band) -> Result<Self, Self::Err>
+    where
+        Self: Sized,
+    {
+        Ok(match b {
+            Band::Black => Self::Black,
+            Band::Brown => Self::Brown,
+            Band::Red => Self::Red,
+            Band::Orange => Self::Orange,
+            Band::Yellow => Self::Yellow,
+            Band::Green => Self::Green,
+            Band::Blue => Self::Blue,
+            Band::Violet => Self::Violet,
+            Band::Grey => Self::Grey,
+            _ => {
+                return Err(BandError::UnsupportedBand(
+                    "temperature coefficient",
+                    b.clone(),
+                ))
+            }
+        })
+    }
+}
+
+impl From<TempCoeff> for Band {
+    fn from(t: TempCoeff) -> Self {
+        match t {
+            TempCoeff::Black => Self::Black,
+            TempCoeff::Brown => Self::Brown,
+            TempCoeff::Red => Self::Red,
+            TempCoeff::Orange => Self::Orange,
+            TempCoeff::Yellow => Self::Yellow,
+            TempCoeff::Green => Self::Green,
+            TempCoeff::Blue => Self::Blue,
+            TempCoeff::Violet => Self::Violet,
+            TempCoeff::Grey => Self::Grey,
+        }
+    }
+}
+
+impl Display for TempCoeff {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} ppm/K", <TempCoeff as Into<u8>>::into(*self))
+    }
+}
+
+impl From<TempCoeff> for u8 {
+    /// Returns the temperature coefficient (in ppm/K) under the classic
+    /// standard.
+    fn from(t: TempCoeff) -> Self {
+        Standard::CLASSIC.temp_coeff(t).unwrap()
+    }
+}
+
+/// Codes, IEC 60757 abbreviations, and names that read as each band.
+const NAMES: [(Band, &[&str]); 13] = [
+    (Band::Black, &["k", "bk", "black"]),
+    (Band::Brown, &["n", "bn", "brown"]),
+    (Band::Red, &["r", "rd", "red"]),
+    (Band::Orange, &["o", "og", "orange"]),
+    (Band::Yellow, &["y", "ye", "yellow"]),
+    (Band::Green, &["g", "gn", "green"]),
+    (Band::Blue, &["b", "bu", "blue"]),
+    (Band::Violet, &["v", "vt", "violet"]),
+    (Band::Grey, &["a", "gy", "grey", "gray"]),
+    (Band::White, &["w", "wh", "white"]),
+    (Band::Gold, &["d", "gd", "gold"]),
+    (Band::Silver, &["s", "sr", "silver"]),
+    (Band::Pink, &["p", "pk", "pink"]),
+];
+
+impl FromStr for Band {
+    type Err = BandError;
+
+    /// Reads a band from its code, abbreviation, or name in any case.
+    fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
+        NAMES
+            .iter()
+            .find(|(_, names)| names.iter().any(|n| n.eq_ignore_ascii_case(s)))
+            .map(|(b, _)| b.clone())
+            .ok_or(BandError::InvalidCode)
+    }
+}
+
+/// A quantity that cannot be marked with a band.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Quantity {
+    Digit(u8),
+    Multiplier(i8),
+    Tolerance(Ppm),
+}
+
+impl Display for Quantity {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Digit(d) => write!(f, "digit {}", d),
+            Self::Multiplier(m) => write!(f, "multiplier 10^{}", m),
+            Self::Tolerance(t) => write!(f, "tolerance {}%", t),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum BandError {
+    InvalidCode,
+    OutOfRange(usize),
+    UnsupportedBand(&'static str, Band),
+    ReversedBandOrder(&'static str, Band),
+    Unrepresentable(Quantity),
+}
+
+impl BandError {
+    /// Hints that the bands decode when read in the opposite direction.
+    pub fn reversed(self) -> Self {
+        match self {
+            Self::UnsupportedBand(role, b) => Self::ReversedBandOrder(role, b),
+            e => e,
+        }
+    }
+}
+
+impl Display for BandError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::InvalidCode => write!(f, "invalid color code"),
+            Self::OutOfRange(n) => write!(f, "color code requires 3 to 6 values but got {}", n),
+            Self::UnsupportedBand(role, b) => {
+                write!(f, "band {:?} is not allowed as a {}", b, role)
+            }
+            Self::ReversedBandOrder(role, b) => write!(
+                f,
+                "band {:?} is not allowed as a {} (hint: try reversing the band order)",
+                b, role
+            ),
+            Self::Unrepresentable(q) => write!(f, "{} cannot be represented with color bands", q),
+        }
+    }
+}
+
+impl core::error::Error for BandError {}
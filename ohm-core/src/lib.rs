@@ -0,0 +1,15 @@
+//! Decoding of resistor color codes without the standard library or an
+//! allocator, for use in firmware as well as by the `ohm` command-line tool.
+#![cfg_attr(not(test), no_std)]
+
+pub mod band;
+mod resistance;
+mod resistor;
+mod standard;
+pub mod value;
+
+pub use band::{Band, BandError, Digit, FromBand, Multiplier, Quantity, TempCoeff, Tolerance};
+pub use resistance::Resistance;
+pub use resistor::Resistor;
+pub use standard::{Standard, StandardError};
+pub use value::{Milliohms, Ppm};
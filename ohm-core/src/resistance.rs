@@ -0,0 +1,60 @@
+use crate::value::{Milliohms, Ppm};
+use core::fmt::Display;
+
+/// The values marked by a resistor's bands, kept as exact integers so no
+/// allocator or decimal arithmetic is needed.
+///
+/// With the `serde` feature, the fields are written in these integer units
+/// (ex: `{"raw":220,"tol":50000,"temp":null}` for 0.22 Ω ± 5%). The `ohm`
+/// crate's `Resistance` instead writes decimal strings in ohms and percent.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resistance {
+    raw: Milliohms,
+    tol: Ppm,
+    temp: Option<u8>,
+}
+
+impl Resistance {
+    pub fn new(raw: Milliohms, tol: Ppm, temp: Option<u8>) -> Self {
+        Self { raw, tol, temp }
+    }
+
+    /// Returns the ideal value.
+    pub fn raw(&self) -> Milliohms {
+        self.raw
+    }
+
+    /// Returns the allowed tolerance.
+    pub fn tolerance(&self) -> Ppm {
+        self.tol
+    }
+
+    /// Returns the temperature coefficient in ppm/K, if marked.
+    pub fn temp_coeff(&self) -> Option<u8> {
+        self.temp
+    }
+}
+
+impl Display for Resistance {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} Ω ± {}%", self.raw, self.tol)?;
+        if let Some(t) = self.temp {
+            write!(f, " {} ppm/K", t)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_display() {
+        let r = Resistance::new(Milliohms(220), Ppm(50_000), None);
+        assert_eq!(r.to_string(), "0.22 Ω ± 5%");
+        let r = Resistance::new(Milliohms(274_000), Ppm(20_000), Some(250));
+        assert_eq!(r.to_string(), "274.0 Ω ± 2% 250 ppm/K");
+    }
+}
@@ -0,0 +1,279 @@
+use crate::band::*;
+use crate::resistance::Resistance;
+use crate::standard::Standard;
+use crate::value::Milliohms;
+
+#[derive(Debug, PartialEq)]
+enum BandLength {
+    L3,
+    L4,
+    L5,
+    L6,
+}
+
+impl From<usize> for BandLength {
+    fn from(f: usize) -> Self {
+        match f {
+            3 => Self::L3,
+            4 => Self::L4,
+            5 => Self::L5,
+            6 => Self::L6,
+            _ => panic!("unsupported band length {}", f),
+        }
+    }
+}
+
+impl From<BandLength> for usize {
+    fn from(l: BandLength) -> Self {
+        match l {
+            BandLength::L3 => 3,
+            BandLength::L4 => 4,
+            BandLength::L5 => 5,
+            BandLength::L6 => 6,
+        }
+    }
+}
+
+/// Minimum support number of colors for a resistor.
+const MIN_CODE_LEN: BandLength = BandLength::L3;
+
+/// Maximum number of colors for a resistor.
+const MAX_CODE_LEN: BandLength = BandLength::L6;
+
+/// The values read from a resistor's color bands.
+#[derive(Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Resistor {
+    first: Digit,
+    second: Digit,
+    third: Option<Digit>,
+    multiplier: Multiplier,
+    tolerance: Tolerance,
+    temp_coeff: Option<TempCoeff>,
+}
+
+impl Resistor {
+    /// Reads the resistor from its sequence of bands, listed from left to right.
+    pub fn decode(bands: &[Band]) -> Result<Self, BandError> {
+        // capture the state of how many bands are specified
+        let band_count = bands.len();
+        let mut bands = bands.iter();
+        if band_count >= MIN_CODE_LEN.into() && band_count <= MAX_CODE_LEN.into() {
+            Ok(Self {
+                first: Digit::from_band(bands.next().unwrap())?,
+                second: Digit::from_band(bands.next().unwrap())?,
+                third: {
+                    match BandLength::from(band_count) {
+                        BandLength::L3 | BandLength::L4 => None,
+                        BandLength::L5 | BandLength::L6 => {
+                            Some(Digit::from_band(bands.next().unwrap())?)
+                        }
+                    }
+                },
+                multiplier: Multiplier::from_band(bands.next().unwrap())?,
+                tolerance: match bands.next() {
+                    Some(b) => Tolerance::from_band(b)?,
+                    None => Tolerance::Default,
+                },
+                temp_coeff: match bands.next() {
+                    Some(b) => Some(TempCoeff::from_band(b)?),
+                    None => None,
+                },
+            })
+        } else {
+            Err(BandError::OutOfRange(band_count))
+        }
+    }
+
+    pub fn first(&self) -> Digit {
+        self.first
+    }
+
+    pub fn second(&self) -> Digit {
+        self.second
+    }
+
+    pub fn third(&self) -> Option<Digit> {
+        self.third
+    }
+
+    pub fn multiplier(&self) -> Multiplier {
+        self.multiplier
+    }
+
+    pub fn tolerance(&self) -> Tolerance {
+        self.tolerance
+    }
+
+    pub fn temp_coeff(&self) -> Option<TempCoeff> {
+        self.temp_coeff
+    }
+
+    /// Computes the ideal value in Ohms described by the digit and multiplier
+    /// bands.
+    pub fn raw(&self) -> Milliohms {
+        let mut result: u32 = 0;
+        // add the first digit
+        result += <Digit as Into<u8>>::into(self.first) as u32;
+
+        // shift digits to the left by 1 position
+        result = (result * 10) + <Digit as Into<u8>>::into(self.second) as u32;
+
+        if let Some(third) = self.third {
+            // shift digits to the left by 1 position
+            result = (result * 10) + <Digit as Into<u8>>::into(third) as u32;
+        }
+
+        // the smallest multiplier is 10^-3, so every value is a whole number
+        // of milliohms
+        let mult: i8 = self.multiplier.into();
+        Milliohms(result as u64 * 10_u64.pow((mult + 3) as u32))
+    }
+
+    /// Computes the resistance described by the bands under the classic
+    /// standard.
+    pub fn resistance(&self) -> Resistance {
+        self.resistance_with(&Standard::CLASSIC).unwrap()
+    }
+
+    /// Computes the resistance described by the bands, reading the tolerance
+    /// and temperature coefficient from the tables of `standard`.
+    pub fn resistance_with(&self, standard: &Standard) -> Result<Resistance, BandError> {
+        Ok(Resistance::new(
+            self.raw(),
+            standard.tolerance(self.tolerance)?,
+            self.temp_coeff
+                .map(|t| standard.temp_coeff(t))
+                .transpose()?,
+        ))
+    }
+
+    #[allow(dead_code)]
+    fn size(&self) -> BandLength {
+        if self.tolerance != Tolerance::Default {
+            if self.third.is_some() {
+                if self.temp_coeff.is_some() {
+                    BandLength::L6
+                } else {
+                    BandLength::L5
+                }
+            } else {
+                BandLength::L4
+            }
+        } else {
+            BandLength::L3
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::value::Ppm;
+    #[test]
+    fn ut_raw_resistance_3_band() {
+        let r = Resistor {
+            first: Digit::Brown,
+            second: Digit::Black,
+            third: None,
+            multiplier: Multiplier::Brown,
+            tolerance: Tolerance::Default,
+            temp_coeff: None,
+        };
+
+        assert_eq!(r.raw(), Milliohms(100_000));
+        assert_eq!(r.size(), BandLength::L3);
+    }
+
+    #[test]
+    fn ut_raw_resistance_4_band() {
+        let r = Resistor {
+            first: Digit::Brown,
+            second: Digit::Red,
+            third: None,
+            multiplier: Multiplier::Green,
+            tolerance: Tolerance::Gold,
+            temp_coeff: None,
+        };
+
+        assert_eq!(r.raw(), Milliohms(1_200_000_000));
+        assert_eq!(r.size(), BandLength::L4);
+    }
+
+    #[test]
+    fn ut_raw_resistance_5_band() {
+        let r = Resistor {
+            first: Digit::Red,
+            second: Digit::Red,
+            third: Some(Digit::Black),
+            multiplier: Multiplier::Black,
+            tolerance: Tolerance::Gold,
+            temp_coeff: None,
+        };
+
+        assert_eq!(r.raw(), Milliohms(220_000));
+        assert_eq!(r.size(), BandLength::L5);
+    }
+
+    #[test]
+    fn ut_raw_resistance_6_band() {
+        let r = Resistor {
+            first: Digit::Red,
+            second: Digit::Violet,
+            third: Some(Digit::Yellow),
+            multiplier: Multiplier::Black,
+            tolerance: Tolerance::Red,
+            temp_coeff: Some(TempCoeff::Black),
+        };
+
+        assert_eq!(r.raw(), Milliohms(274_000));
+        assert_eq!(r.size(), BandLength::L6);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn ut_serde() {
+        let r = Resistor::decode(&[Band::Yellow, Band::Violet, Band::Grey, Band::Gold]).unwrap();
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(
+            json,
+            r#"{"first":"yellow","second":"violet","third":null,"multiplier":"grey","tolerance":"gold","temp_coeff":null}"#
+        );
+        assert_eq!(serde_json::from_str::<Resistor>(&json).unwrap(), r);
+        // the default tolerance has no band
+        let r = Resistor::decode(&[Band::Red, Band::Red, Band::Red]).unwrap();
+        let json = serde_json::to_string(&r).unwrap();
+        assert!(json.contains(r#""tolerance":null"#));
+        assert_eq!(serde_json::from_str::<Resistor>(&json).unwrap(), r);
+        assert!(serde_json::from_str::<Resistor>(
+            &json.replace(r#""tolerance":null"#, r#""tolerance":"black""#)
+        )
+        .is_err());
+        let res = Resistor::decode(&[Band::Red, Band::Red, Band::Silver, Band::Gold])
+            .unwrap()
+            .resistance();
+        let json = serde_json::to_string(&res).unwrap();
+        assert_eq!(json, r#"{"raw":220,"tol":50000,"temp":null}"#);
+        assert_eq!(serde_json::from_str::<Resistance>(&json).unwrap(), res);
+    }
+
+    #[test]
+    fn ut_decode() {
+        let r = Resistor::decode(&[Band::Brown, Band::Black, Band::Red, Band::Gold]).unwrap();
+        assert_eq!(
+            r.resistance(),
+            Resistance::new(Milliohms(1_000_000), Ppm(50_000), None)
+        );
+        // a current-sense resistor with a pink multiplier
+        let r = Resistor::decode(&[Band::Blue, Band::Grey, Band::Pink, Band::Brown]).unwrap();
+        assert_eq!(r.raw(), Milliohms(68));
+        assert_eq!(
+            Resistor::decode(&[Band::Brown, Band::Black]),
+            Err(BandError::OutOfRange(2))
+        );
+        assert_eq!(
+            Resistor::decode(&[Band::Gold, Band::Red, Band::Black, Band::Brown]),
+            Err(BandError::UnsupportedBand("digit", Band::Gold))
+        );
+    }
+}
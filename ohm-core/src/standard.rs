@@ -0,0 +1,253 @@
+use core::fmt::Display;
+use core::str::FromStr;
+
+use crate::band::{BandError, Quantity, TempCoeff, Tolerance};
+use crate::value::Ppm;
+
+/// The values that the tolerance and temperature coefficient bands stand for.
+///
+/// These tables differ between revisions of IEC 60062 and between vendors, so
+/// a table missing from the presets can be built as a `Standard` of its own.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Standard {
+    /// Short name used to select the standard.
+    name: &'static str,
+    /// Full name reported alongside the results.
+    title: &'static str,
+    /// Tolerance of each tolerance band, in the order of the [Tolerance]
+    /// variants.
+    tolerances: [Option<Ppm>; 10],
+    /// Tolerance of a resistor without a tolerance band.
+    default_tolerance: Ppm,
+    /// Temperature coefficient (in ppm/K) of each temperature coefficient
+    /// band, in the order of the [TempCoeff] variants.
+    temp_coeffs: [Option<u8>; 9],
+}
+
+impl Standard {
+    /// The color code found on most charts, which predates IEC 60062:2016.
+    pub const CLASSIC: Self = Self::new("classic", "classic color code")
+        .with_tolerance(Tolerance::Brown, Ppm(10_000))
+        .with_tolerance(Tolerance::Red, Ppm(20_000))
+        .with_tolerance(Tolerance::Orange, Ppm(30_000))
+        .with_tolerance(Tolerance::Yellow, Ppm(40_000))
+        .with_tolerance(Tolerance::Green, Ppm(5_000))
+        .with_tolerance(Tolerance::Blue, Ppm(2_500))
+        .with_tolerance(Tolerance::Violet, Ppm(1_000))
+        .with_tolerance(Tolerance::Grey, Ppm(500))
+        .with_tolerance(Tolerance::Gold, Ppm(50_000))
+        .with_tolerance(Tolerance::Silver, Ppm(100_000))
+        .with_tolerance(Tolerance::Default, Ppm(200_000))
+        .with_iec_temp_coeffs();
+
+    /// The color code of IEC 60062:2016, which gives orange, yellow, and grey
+    /// tolerance bands to precision parts. Its temperature coefficients are
+    /// those of the classic charts, so only the tolerance bands read
+    /// differently.
+    pub const IEC_60062_2016: Self = Self::CLASSIC
+        .renamed("iec60062-2016", "IEC 60062:2016")
+        .with_tolerance(Tolerance::Orange, Ppm(500))
+        .with_tolerance(Tolerance::Yellow, Ppm(200))
+        .with_tolerance(Tolerance::Grey, Ppm(100));
+
+    /// The built-in presets.
+    pub const ALL: [Self; 2] = [Self::CLASSIC, Self::IEC_60062_2016];
+
+    /// Starts a standard with empty tables and a default tolerance of 20%.
+    pub const fn new(name: &'static str, title: &'static str) -> Self {
+        Self {
+            name,
+            title,
+            tolerances: [None; 10],
+            default_tolerance: Ppm(200_000),
+            temp_coeffs: [None; 9],
+        }
+    }
+
+    /// Gives the standard another name, keeping its tables.
+    pub const fn renamed(mut self, name: &'static str, title: &'static str) -> Self {
+        self.name = name;
+        self.title = title;
+        self
+    }
+
+    /// Sets the tolerance marked by `tol`, or the tolerance of a resistor
+    /// without a tolerance band for [Tolerance::Default].
+    pub const fn with_tolerance(mut self, tol: Tolerance, value: Ppm) -> Self {
+        match tol {
+            Tolerance::Default => self.default_tolerance = value,
+            t => self.tolerances[t as usize] = Some(value),
+        }
+        self
+    }
+
+    /// Sets the temperature coefficient (in ppm/K) marked by `tc`.
+    pub const fn with_temp_coeff(mut self, tc: TempCoeff, value: u8) -> Self {
+        self.temp_coeffs[tc as usize] = Some(value);
+        self
+    }
+
+    /// Fills in the temperature coefficients that IEC 60062 assigns, which
+    /// the presets share. Charts that assign other coefficients need a
+    /// custom standard.
+    const fn with_iec_temp_coeffs(self) -> Self {
+        self.with_temp_coeff(TempCoeff::Black, 250)
+            .with_temp_coeff(TempCoeff::Brown, 100)
+            .with_temp_coeff(TempCoeff::Red, 50)
+            .with_temp_coeff(TempCoeff::Orange, 15)
+            .with_temp_coeff(TempCoeff::Yellow, 25)
+            .with_temp_coeff(TempCoeff::Green, 20)
+            .with_temp_coeff(TempCoeff::Blue, 10)
+            .with_temp_coeff(TempCoeff::Violet, 5)
+            .with_temp_coeff(TempCoeff::Grey, 1)
+    }
+
+    /// Returns the short name used to select the standard.
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// Returns the full name reported alongside the results.
+    pub fn title(&self) -> &'static str {
+        self.title
+    }
+
+    /// Looks up the tolerance marked by `tol`, failing when the band is
+    /// missing from the table.
+    pub fn tolerance(&self, tol: Tolerance) -> Result<Ppm, BandError> {
+        match tol.band() {
+            Some(b) => {
+                self.tolerances[tol as usize].ok_or(BandError::UnsupportedBand("tolerance", b))
+            }
+            None => Ok(self.default_tolerance),
+        }
+    }
+
+    /// Finds the tolerance band that marks a tolerance of `value`.
+    pub fn tolerance_band(&self, value: Ppm) -> Result<Tolerance, BandError> {
+        match value == self.default_tolerance {
+            true => Ok(Tolerance::Default),
+            false => Tolerance::ALL
+                .into_iter()
+                .find(|t| self.tolerances[*t as usize] == Some(value))
+                .ok_or(BandError::Unrepresentable(Quantity::Tolerance(value))),
+        }
+    }
+
+    /// Looks up the temperature coefficient (in ppm/K) marked by `tc`, failing
+    /// when the band is missing from the table.
+    pub fn temp_coeff(&self, tc: TempCoeff) -> Result<u8, BandError> {
+        self.temp_coeffs[tc as usize].ok_or(BandError::UnsupportedBand(
+            "temperature coefficient",
+            tc.into(),
+        ))
+    }
+}
+
+impl Default for Standard {
+    fn default() -> Self {
+        Self::CLASSIC
+    }
+}
+
+impl FromStr for Standard {
+    type Err = StandardError;
+
+    /// Selects a preset by its name in any case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|p| p.name.eq_ignore_ascii_case(s.trim()))
+            .ok_or(StandardError::Unknown)
+    }
+}
+
+impl Display for Standard {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.title)
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum StandardError {
+    Unknown,
+}
+
+impl Display for StandardError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Unknown => write!(f, "unknown standard (expects classic or iec60062-2016)"),
+        }
+    }
+}
+
+impl core::error::Error for StandardError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::band::Band;
+
+    #[test]
+    fn ut_standard_tables() {
+        let iec = Standard::from_str("IEC60062-2016").unwrap();
+        assert_eq!(iec.tolerance(Tolerance::Grey).unwrap(), Ppm(100));
+        assert_eq!(
+            Standard::default().tolerance(Tolerance::Grey).unwrap(),
+            Ppm(500)
+        );
+        assert_eq!(iec.tolerance(Tolerance::Default).unwrap(), Ppm(200_000));
+        assert_eq!(iec.tolerance_band(Ppm(200)).unwrap(), Tolerance::Yellow);
+        assert!(Standard::CLASSIC.tolerance_band(Ppm(200)).is_err());
+        assert_eq!(iec.temp_coeff(TempCoeff::Orange).unwrap(), 15);
+        assert_eq!(Standard::from_str("eia"), Err(StandardError::Unknown));
+        // the tables are indexed in the order of the variants
+        for (i, t) in Tolerance::ALL.into_iter().enumerate() {
+            assert_eq!(t as usize, i);
+        }
+    }
+
+    #[test]
+    fn ut_six_bands_by_standard() {
+        use crate::Resistor;
+        // brown black black red, grey tolerance, orange temp. coeff.
+        let r = Resistor::decode(&[
+            Band::Brown,
+            Band::Black,
+            Band::Black,
+            Band::Red,
+            Band::Grey,
+            Band::Orange,
+        ])
+        .unwrap();
+        let classic = r.resistance_with(&Standard::CLASSIC).unwrap();
+        let iec = r.resistance_with(&Standard::IEC_60062_2016).unwrap();
+        assert_eq!(classic.tolerance(), Ppm(500));
+        assert_eq!(iec.tolerance(), Ppm(100));
+        // the presets share the temperature coefficients
+        assert_eq!(classic.temp_coeff(), Some(15));
+        assert_eq!(iec.temp_coeff(), Some(15));
+        assert_eq!(classic.raw(), iec.raw());
+    }
+
+    #[test]
+    fn ut_custom_standard() {
+        // a vendor table that swaps orange and yellow and knows no others
+        let vendor = Standard::new("vendor", "vendor")
+            .with_tolerance(Tolerance::Gold, Ppm(50_000))
+            .with_temp_coeff(TempCoeff::Orange, 25)
+            .with_temp_coeff(TempCoeff::Yellow, 15);
+        assert_eq!(vendor.temp_coeff(TempCoeff::Yellow).unwrap(), 15);
+        assert_eq!(
+            vendor.temp_coeff(TempCoeff::Red),
+            Err(BandError::UnsupportedBand(
+                "temperature coefficient",
+                Band::Red
+            ))
+        );
+        assert_eq!(
+            vendor.tolerance(Tolerance::Brown),
+            Err(BandError::UnsupportedBand("tolerance", Band::Brown))
+        );
+    }
+}
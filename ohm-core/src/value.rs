@@ -0,0 +1,69 @@
+use core::fmt::Display;
+
+/// A resistance in thousandths of an Ohm, which holds every value the bands
+/// can mark (1 mΩ to 999 GΩ) exactly.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Milliohms(pub u64);
+
+impl Display for Milliohms {
+    /// Writes the value in Ohms with at least one decimal place (ex: `1000.0`
+    /// or `0.22`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}.", self.0 / 1_000)?;
+        match self.0 % 1_000 {
+            0 => write!(f, "0"),
+            frac => fraction(f, frac as u32, 3),
+        }
+    }
+}
+
+/// A tolerance in parts per million of the nominal value (ex: 5% is
+/// 50,000 ppm).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(transparent))]
+pub struct Ppm(pub u32);
+
+impl Display for Ppm {
+    /// Writes the value as a percentage without trailing zeros (ex: `5` or
+    /// `0.05`).
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.0 / 10_000)?;
+        match self.0 % 10_000 {
+            0 => Ok(()),
+            frac => {
+                write!(f, ".")?;
+                fraction(f, frac, 4)
+            }
+        }
+    }
+}
+
+/// Writes the `places` decimal digits of a nonzero `frac` without trailing
+/// zeros.
+fn fraction(f: &mut core::fmt::Formatter<'_>, mut frac: u32, mut places: u32) -> core::fmt::Result {
+    while frac.is_multiple_of(10) {
+        frac /= 10;
+        places -= 1;
+    }
+    write!(f, "{:0width$}", frac, width = places as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_display() {
+        assert_eq!(Milliohms(4_700_000).to_string(), "4700.0");
+        assert_eq!(Milliohms(220).to_string(), "0.22");
+        assert_eq!(Milliohms(68).to_string(), "0.068");
+        assert_eq!(Milliohms(1_500).to_string(), "1.5");
+        assert_eq!(Ppm(50_000).to_string(), "5");
+        assert_eq!(Ppm(500).to_string(), "0.05");
+        assert_eq!(Ppm(2_500).to_string(), "0.25");
+        assert_eq!(Ppm(100).to_string(), "0.01");
+    }
+}
@@ -0,0 +1,127 @@
+use crate::band::{self, Band};
+use crate::locale::Locale;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// An extra name for a band, such as a shorthand used in a lab's notebooks.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Alias {
+    name: String,
+    band: Band,
+}
+
+impl Alias {
+    /// Creates an alias that reads `name` as `band`, failing when `name`
+    /// already stands for another band in any language.
+    pub fn new(name: &str, band: Band) -> Result<Self, AliasError> {
+        let name = name.trim().to_lowercase();
+        // hex colors are always read as colors
+        if name.is_empty() || name.starts_with('#') || name.contains(char::is_whitespace) {
+            return Err(AliasError::InvalidName(name));
+        }
+        let builtin = Locale::ALL
+            .iter()
+            .filter_map(|l| l.parse(&name).ok())
+            .find(|b| b != &band);
+        match builtin {
+            Some(b) => Err(AliasError::Conflict(name, b, band)),
+            None => Ok(Self { name, band }),
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn band(&self) -> &Band {
+        &self.band
+    }
+
+    /// Finds the band named by `s` among the `aliases`.
+    pub fn lookup<'a>(aliases: &'a [Alias], s: &str) -> Option<&'a Band> {
+        let text = s.trim().to_lowercase();
+        aliases.iter().find(|a| a.name == text).map(|a| &a.band)
+    }
+}
+
+impl FromStr for Alias {
+    type Err = AliasError;
+
+    /// Reads an alias written as `<name>=<color>` (ex: `au=gold`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (name, color) = s
+            .split_once('=')
+            .ok_or(AliasError::Malformed(s.to_string()))?;
+        let band =
+            band::parse(color.trim()).map_err(|_| AliasError::UnknownColor(color.to_string()))?;
+        Self::new(name, band)
+    }
+}
+
+impl Display for Alias {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}={}", self.name, self.band.ascii())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AliasError {
+    #[error("malformed alias {0:?} (expects <name>=<color>)")]
+    Malformed(String),
+    #[error("invalid alias name {0:?}")]
+    InvalidName(String),
+    #[error("unknown color {0:?} for an alias")]
+    UnknownColor(String),
+    #[error("alias {0:?} conflicts with the built-in name for {1:?} (cannot also mean {2:?})")]
+    Conflict(String, Band, Band),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_parse_alias() {
+        let alias = Alias::from_str("AU=gold").unwrap();
+        assert_eq!(alias.name(), "au");
+        assert_eq!(alias.band(), &Band::Gold);
+        assert_eq!(alias.to_string(), "au=gold");
+        // repeating a built-in name for the same band is harmless
+        assert_eq!(Alias::from_str("gy=grey").unwrap().band(), &Band::Grey);
+        assert!(matches!(
+            Alias::from_str("au"),
+            Err(AliasError::Malformed(_))
+        ));
+        assert!(matches!(
+            Alias::from_str("au=copper"),
+            Err(AliasError::UnknownColor(_))
+        ));
+    }
+
+    #[test]
+    fn ut_alias_conflict() {
+        // 'r' is already red
+        assert!(matches!(
+            Alias::new("r", Band::Brown),
+            Err(AliasError::Conflict(_, Band::Red, Band::Brown))
+        ));
+        // 'rot' is red in German and 'ピンク' is pink in Japanese
+        assert!(matches!(
+            Alias::new("rot", Band::Blue),
+            Err(AliasError::Conflict(_, Band::Red, Band::Blue))
+        ));
+        assert!(Alias::new("rot", Band::Red).is_ok());
+        assert!(matches!(
+            Alias::new("ピンク", Band::Red),
+            Err(AliasError::Conflict(_, Band::Pink, Band::Red))
+        ));
+        assert!(matches!(
+            Alias::new("#f00", Band::Red),
+            Err(AliasError::InvalidName(_))
+        ));
+        let aliases = vec![Alias::new("au", Band::Gold).unwrap()];
+        assert_eq!(Alias::lookup(&aliases, " Au "), Some(&Band::Gold));
+        assert_eq!(Alias::lookup(&aliases, "ag"), None);
+    }
+}
@@ -1,366 +1,129 @@
-use crayon::Color;
-use std::fmt::Display;
+use crate::color::{self, Rgb};
+use crate::ohm::Precision;
+use crate::value;
+use ohm_core::Standard;
 use std::str::FromStr;
 use thiserror::Error;
 
-use crate::ohm::Precision;
-
-#[derive(Debug, PartialEq, Clone)]
-pub enum Band {
-    Black,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Violet,
-    Grey,
-    White,
-    Gold,
-    Silver,
-}
-
-impl Band {
-    pub fn ascii(&self) -> &str {
-        match self {
-            Self::Black => "black",
-            Self::Brown => "brown",
-            Self::Red => "red",
-            Self::Orange => "orange",
-            Self::Yellow => "yellow",
-            Self::Green => "green",
-            Self::Blue => "blue",
-            Self::Violet => "violet",
-            Self::Grey => "grey",
-            Self::White => "white",
-            Self::Gold => "gold",
-            Self::Silver => "silver",
-        }
-    }
-}
-
-/// Thick vertical line.
-const BAND_ART: &str = "\u{2503}";
-
-impl Display for Band {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            match self {
-                Self::Black => BAND_ART.black(),
-                Self::Brown => BAND_ART.rgb(0x96, 0x4B, 0x00),
-                Self::Red => BAND_ART.red(),
-                Self::Orange => BAND_ART.rgb(0xFF, 0xA5, 0x00),
-                Self::Yellow => BAND_ART.yellow(),
-                Self::Green => BAND_ART.green(),
-                Self::Blue => BAND_ART.blue(),
-                Self::Violet => BAND_ART.magenta(),
-                Self::Grey => BAND_ART.rgb(0x80, 0x80, 0x80),
-                Self::White => BAND_ART.white(),
-                Self::Gold => BAND_ART.rgb(0xFF, 0xD7, 0x00),
-                Self::Silver => BAND_ART.rgb(0xAA, 0xA9, 0xAD),
-            }
-        )
+pub use ohm_core::band::*;
+pub use ohm_core::Resistor;
+
+/// Reads a band from its code, abbreviation, or name, or takes the band
+/// closest to a hex color (ex: #964B00).
+pub fn parse(s: &str) -> Result<Band, BandError> {
+    match s.starts_with('#') {
+        true => match Rgb::from_str(s) {
+            Ok(c) => Ok(color::nearest(c).0),
+            Err(_) => Err(BandError::InvalidCode),
+        },
+        false => Band::from_str(s),
     }
 }
 
-pub trait FromBand {
-    type Err;
-
-    fn from_band(b: &Band) -> Result<Self, Self::Err>
-    where
-        Self: Sized;
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum Digit {
-    Black,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Violet,
-    Grey,
-    White,
-}
-
-impl FromBand for Digit {
-    type Err = BandError;
-
-    fn from_band(b: &Band) -> Result<Self, Self::Err>
-    where
-        Self: Sized,
-    {
-        Ok(match b {
-            Band::Black => Self::Black,
-            Band::Brown => Self::Brown,
-            Band::Red => Self::Red,
-            Band::Orange => Self::Orange,
-            Band::Yellow => Self::Yellow,
-            Band::Green => Self::Green,
-            Band::Blue => Self::Blue,
-            Band::Violet => Self::Violet,
-            Band::Grey => Self::Grey,
-            Band::White => Self::White,
-            _ => return Err(BandError::UnsupportedBand(String::from("digit"), b.clone())),
-        })
-    }
-}
-
-impl Into<u8> for Digit {
-    fn into(self) -> u8 {
-        match self {
-            Self::Black => 0,
-            Self::Brown => 1,
-            Self::Red => 2,
-            Self::Orange => 3,
-            Self::Yellow => 4,
-            Self::Green => 5,
-            Self::Blue => 6,
-            Self::Violet => 7,
-            Self::Grey => 8,
-            Self::White => 9,
+/// Reads the resistor from its sequence of bands, hinting when the bands
+/// only make sense in the opposite order.
+pub fn read(bands: &[Band]) -> Result<Resistor, BandError> {
+    Resistor::decode(bands).map_err(|e| {
+        let mut rev_bands = bands.to_vec();
+        rev_bands.reverse();
+        match Resistor::decode(&rev_bands) {
+            // the bands were entered in reverse order
+            Ok(_) => e.reversed(),
+            // the bands are just flat-out wrong
+            Err(_) => e,
         }
+    })
+}
+
+/// Finds the band that marks a tolerance of `percent` under `standard`.
+pub fn tolerance(percent: Precision, standard: &Standard) -> Result<Tolerance, EncodeError> {
+    let ppm = value::ppm(percent).ok_or(EncodeError::Tolerance(percent))?;
+    Ok(standard.tolerance_band(ppm)?)
+}
+
+/// Writes `value` as a sequence of bands using `digits` significant digits
+/// followed by the multiplier and `tolerance` bands.
+pub fn encode(
+    value: Precision,
+    digits: usize,
+    tolerance: Tolerance,
+) -> Result<Vec<Band>, EncodeError> {
+    let inexact = || EncodeError::Resistance(value);
+    // a 3-digit code must be followed by a tolerance band to be decoded
+    if !(2..=3).contains(&digits) || (digits == 3 && tolerance == Tolerance::Default) {
+        return Err(inexact());
     }
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum Multiplier {
-    Black,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Violet,
-    Grey,
-    White,
-    Gold,
-    Silver,
-}
-
-impl FromBand for Multiplier {
-    type Err = BandError;
-
-    fn from_band(b: &Band) -> Result<Self, Self::Err>
-    where
-        Self: Sized,
-    {
-        Ok(match b {
-            Band::Black => Self::Black,
-            Band::Brown => Self::Brown,
-            Band::Red => Self::Red,
-            Band::Orange => Self::Orange,
-            Band::Yellow => Self::Yellow,
-            Band::Green => Self::Green,
-            Band::Blue => Self::Blue,
-            Band::Violet => Self::Violet,
-            Band::Grey => Self::Grey,
-            Band::White => Self::White,
-            Band::Gold => Self::Gold,
-            Band::Silver => Self::Silver,
-        })
+    // find the exponent that leaves exactly `digits` digits before the decimal point
+    let (sig, exp) = value::significand(value, digits as u32).ok_or_else(inexact)?;
+    let mut bands = Vec::with_capacity(digits + 2);
+    for place in (0..digits as u32).rev() {
+        let digit = Digit::try_from((sig / 10_u32.pow(place) % 10) as u8)?;
+        bands.push(Band::from(digit));
     }
-}
-
-impl Into<i8> for Multiplier {
-    fn into(self) -> i8 {
-        match self {
-            Self::Black => 0,
-            Self::Brown => 1,
-            Self::Red => 2,
-            Self::Orange => 3,
-            Self::Yellow => 4,
-            Self::Green => 5,
-            Self::Blue => 6,
-            Self::Violet => 7,
-            Self::Grey => 8,
-            Self::White => 9,
-            Self::Gold => -1,
-            Self::Silver => -2,
-        }
+    let mult = i8::try_from(exp).map_err(|_| inexact())?;
+    bands.push(Band::from(Multiplier::try_from(mult)?));
+    if let Some(b) = tolerance.band() {
+        bands.push(b);
     }
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum Tolerance {
-    // Black,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Violet,
-    Grey,
-    // White,
-    Gold,
-    Silver,
-    /// Default tolerance for a 3-band resistor.
-    Default,
-}
-
-impl FromBand for Tolerance {
-    type Err = BandError;
-
-    fn from_band(b: &Band) -> Result<Self, Self::Err>
-    where
-        Self: Sized,
-    {
-        Ok(match b {
-            Band::Brown => Self::Brown,
-            Band::Red => Self::Red,
-            Band::Orange => Self::Orange,
-            Band::Yellow => Self::Yellow,
-            Band::Green => Self::Green,
-            Band::Blue => Self::Blue,
-            Band::Violet => Self::Violet,
-            Band::Grey => Self::Grey,
-            Band::Gold => Self::Gold,
-            Band::Silver => Self::Silver,
-            _ => {
-                return Err(BandError::UnsupportedBand(
-                    String::from("tolerance"),
-                    b.clone(),
-                ))
-            }
-        })
-    }
-}
-
-impl Into<Precision> for Tolerance {
-    fn into(self) -> Precision {
-        match self {
-            Self::Brown => 1.0,
-            Self::Red => 2.0,
-            Self::Orange => 3.0,
-            Self::Yellow => 4.0,
-            Self::Green => 0.5,
-            Self::Blue => 0.25,
-            Self::Violet => 0.1,
-            Self::Grey => 0.05,
-            Self::Gold => 5.0,
-            Self::Silver => 10.0,
-            Self::Default => 20.0,
-        }
-    }
-}
-
-#[derive(Debug, PartialEq, Clone, Copy)]
-pub enum TempCoeff {
-    Black,
-    Brown,
-    Red,
-    Orange,
-    Yellow,
-    Green,
-    Blue,
-    Violet,
-    Grey,
-    // White,
-    // Gold,
-    // Silver
-}
-
-impl FromBand for TempCoeff {
-    type Err = BandError;
-
-    fn from_band(b: &Band) -> Result<Self, Self::Err>
-    where
-        Self: Sized,
-    {
-        Ok(match b {
-            Band::Black => Self::Black,
-            Band::Brown => Self::Brown,
-            Band::Red => Self::Red,
-            Band::Orange => Self::Orange,
-            Band::Yellow => Self::Yellow,
-            Band::Green => Self::Green,
-            Band::Blue => Self::Blue,
-            Band::Violet => Self::Violet,
-            Band::Grey => Self::Grey,
-            _ => {
-                return Err(BandError::UnsupportedBand(
-                    String::from("temperature coefficient"),
-                    b.clone(),
-                ))
-            }
-        })
+    // verify the bands exactly describe the value
+    match value::ohms(Resistor::decode(&bands)?.raw()) == value {
+        true => Ok(bands),
+        false => Err(inexact()),
     }
 }
 
-impl Display for TempCoeff {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "{} ppm/K", <TempCoeff as Into<u8>>::into(*self))
-    }
-}
-
-impl Into<u8> for TempCoeff {
-    fn into(self) -> u8 {
-        match self {
-            Self::Black => 250,
-            Self::Brown => 100,
-            Self::Red => 50,
-            Self::Orange => 15,
-            Self::Yellow => 25,
-            Self::Green => 20,
-            Self::Blue => 10,
-            Self::Violet => 5,
-            Self::Grey => 1,
-        }
-    }
-}
-
-impl FromStr for Band {
-    type Err = BandError;
-
-    fn from_str(s: &str) -> Result<Self, <Self as FromStr>::Err> {
-        Ok(match s.to_ascii_lowercase().as_ref() {
-            "k" | "black" => Self::Black,
-            "n" | "brown" => Self::Brown,
-            "r" | "red" => Self::Red,
-            "o" | "orange" => Self::Orange,
-            "y" | "yellow" => Self::Yellow,
-            "g" | "green" => Self::Green,
-            "b" | "blue" => Self::Blue,
-            "v" | "violet" => Self::Violet,
-            "a" | "grey" | "gray" => Self::Grey,
-            "w" | "white" => Self::White,
-            "d" | "gold" => Self::Gold,
-            "s" | "silver" => Self::Silver,
-            _ => return Err(BandError::InvalidCode),
-        })
-    }
+#[derive(Error, Debug, PartialEq)]
+pub enum EncodeError {
+    #[error(transparent)]
+    Band(#[from] BandError),
+    #[error("{} Ω cannot be represented with color bands", value::plain(*.0))]
+    Resistance(Precision),
+    #[error("tolerance {}% cannot be represented with color bands", .0.normalize())]
+    Tolerance(Precision),
 }
 
 pub const BAND_LIST: &str = "\
 Color Codes:
-    k   black       0
-    n   brown       1
-    r   red         2
-    o   orange      3
-    y   yellow      4
-    g   green       5
-    b   blue        6
-    v   violet      7
-    a   gray        8
-    w   white       9
-    d   gold
-    s   silver";
-
-#[derive(Error, Debug)]
-pub enum BandError {
-    #[error("invalid color code")]
-    InvalidCode,
-    #[error("color code requires 3 to 6 values but got {0}")]
-    OutOfRange(usize),
-    #[error("band {1:?} is not allowed as a {0}")]
-    UnsupportedBand(String, Band),
-    #[error("{0} (hint: try reversing the band order)")]
-    ReversedBandOrder(String),
+    k   BK   black       0
+    n   BN   brown       1
+    r   RD   red         2
+    o   OG   orange      3
+    y   YE   yellow      4
+    g   GN   green       5
+    b   BU   blue        6
+    v   VT   violet      7
+    a   GY   gray        8
+    w   WH   white       9
+    d   GD   gold
+    s   SR   silver
+    p   PK   pink";
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn ut_encode() {
+        assert_eq!(
+            encode(dec!(4700), 2, Tolerance::Gold).unwrap(),
+            vec![Band::Yellow, Band::Violet, Band::Red, Band::Gold]
+        );
+        assert_eq!(
+            encode(dec!(0.47), 2, Tolerance::Default).unwrap(),
+            vec![Band::Yellow, Band::Violet, Band::Silver]
+        );
+        assert_eq!(
+            encode(dec!(10_200), 3, Tolerance::Brown).unwrap(),
+            vec![Band::Brown, Band::Black, Band::Red, Band::Red, Band::Brown]
+        );
+        // too many significant digits
+        assert!(encode(dec!(4750), 2, Tolerance::Gold).is_err());
+        assert_eq!(
+            encode(dec!(0.047), 2, Tolerance::Brown).unwrap(),
+            vec![Band::Yellow, Band::Violet, Band::Pink, Band::Brown]
+        );
+        // too small for the smallest multiplier
+        assert!(encode(dec!(0.001), 2, Tolerance::Gold).is_err());
+    }
 }
@@ -0,0 +1,275 @@
+use crate::band::{self, Band};
+use crate::eseries::ESeries;
+use crate::ohm::Precision;
+use crate::smd;
+use crate::value::{Percent, Value};
+use ohm_core::Standard;
+use rust_decimal_macros::dec;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Column names (in lowercase) that list the reference designators.
+const REFERENCE_COLUMNS: [&str; 6] = [
+    "reference",
+    "references",
+    "ref",
+    "refdes",
+    "designator",
+    "designators",
+];
+
+/// Column names (in lowercase) that hold the part's value.
+const VALUE_COLUMNS: [&str; 3] = ["value", "val", "comment"];
+
+/// Column names (in lowercase) that hold the part's tolerance.
+const TOLERANCE_COLUMNS: [&str; 2] = ["tolerance", "tol"];
+
+/// Columns appended to each row of an annotated BOM.
+const ANNOTATION_COLUMNS: [&str; 4] = ["Bands", "SMD Code", "E-Series", "Review"];
+
+/// A bill of materials read from a CSV file.
+#[derive(Debug, PartialEq)]
+pub struct Bom {
+    header: Vec<String>,
+    rows: Vec<Vec<String>>,
+    references: usize,
+    value: usize,
+    tolerance: Option<usize>,
+}
+
+impl Bom {
+    /// Reads a BOM from the contents of a CSV file.
+    pub fn parse(text: &str) -> Result<Self, BomError> {
+        let mut records = parse_csv(text).into_iter();
+        let header = records.next().ok_or(BomError::Empty)?;
+        let find = |names: &[&str]| {
+            header
+                .iter()
+                .position(|h| names.contains(&h.trim().to_lowercase().as_ref()))
+        };
+        Ok(Self {
+            references: find(&REFERENCE_COLUMNS).ok_or(BomError::MissingColumn("reference"))?,
+            value: find(&VALUE_COLUMNS).ok_or(BomError::MissingColumn("value"))?,
+            tolerance: find(&TOLERANCE_COLUMNS),
+            rows: records.collect(),
+            header,
+        })
+    }
+
+    /// Writes the BOM as CSV with the color bands, SMD code, and E-series of
+    /// each resistor added to its row. Parts given without a tolerance are
+    /// assumed to be `default_tol` percent, and tolerance bands are read from
+    /// the tables of `standard`.
+    pub fn annotate(&self, default_tol: Precision, standard: &Standard) -> String {
+        let mut header = self.header.clone();
+        header.extend(ANNOTATION_COLUMNS.iter().map(|c| c.to_string()));
+        let mut text = write_csv_row(&header);
+        for row in &self.rows {
+            let cell = |i: usize| row.get(i).map(|s| s.trim()).unwrap_or_default();
+            let mut out = row.clone();
+            out.resize(self.header.len(), String::new());
+            let notes = match is_resistor(cell(self.references)) {
+                true => {
+                    let tol = self.tolerance.map(cell).filter(|t| !t.is_empty());
+                    Annotation::new(cell(self.value), tol, default_tol, standard).into_row()
+                }
+                false => vec![String::new(); ANNOTATION_COLUMNS.len()],
+            };
+            out.extend(notes);
+            text.push_str(&write_csv_row(&out));
+        }
+        text
+    }
+}
+
+/// The information added to a resistor's row of the BOM.
+#[derive(Debug, PartialEq, Default)]
+struct Annotation {
+    bands: Option<Vec<Band>>,
+    smd: Option<String>,
+    series: Option<ESeries>,
+    review: Vec<&'static str>,
+}
+
+impl Annotation {
+    fn new(value: &str, tol: Option<&str>, default_tol: Precision, standard: &Standard) -> Self {
+        let mut notes = Self::default();
+        let value = match Value::from_str(value) {
+            Ok(v) => v.ohms(),
+            Err(_) => {
+                notes.review.push("unparsable value");
+                return notes;
+            }
+        };
+        let tol = match tol.map(Percent::from_str) {
+            Some(Ok(p)) => p.value(),
+            Some(Err(_)) => {
+                notes.review.push("unparsable tolerance");
+                return notes;
+            }
+            None => default_tol,
+        };
+        notes.bands = Self::bands(value, tol, standard);
+        if notes.bands.is_none() {
+            notes.review.push("no color code");
+        }
+        notes.smd = smd::encode(value, tol).ok();
+        if notes.smd.is_none() {
+            notes.review.push("no SMD code");
+        }
+        notes.series = ESeries::smallest_containing(value);
+        if notes.series.is_none() {
+            notes.review.push("non-standard value");
+        }
+        notes
+    }
+
+    /// Finds the color code for the part, using 4 bands for wide tolerances
+    /// and 5 bands for precision parts.
+    fn bands(value: Precision, tol: Precision, standard: &Standard) -> Option<Vec<Band>> {
+        let tolerance = band::tolerance(tol, standard).ok()?;
+        let digits = match tol > dec!(2) {
+            true => 2,
+            false => 3,
+        };
+        band::encode(value, digits, tolerance)
+            .or_else(|_| band::encode(value, 3, tolerance))
+            .ok()
+    }
+
+    fn into_row(self) -> Vec<String> {
+        vec![
+            self.bands
+                .map(|b| b.iter().map(|b| b.ascii()).collect::<Vec<&str>>().join(" "))
+                .unwrap_or_default(),
+            self.smd.unwrap_or_default(),
+            self.series.map(|s| s.to_string()).unwrap_or_default(),
+            self.review.join("; "),
+        ]
+    }
+}
+
+/// Checks if the reference designators of a row belong to resistors.
+fn is_resistor(refs: &str) -> bool {
+    let mut chars = refs.chars();
+    matches!(chars.next(), Some('R' | 'r')) && chars.next().is_some_and(|c| c.is_ascii_digit())
+}
+
+/// Splits CSV text into records of fields, handling quoted fields that
+/// contain commas, quotes, or line breaks.
+fn parse_csv(text: &str) -> Vec<Vec<String>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut quoted = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (c, quoted) {
+            ('"', true) => match chars.peek() {
+                // an escaped quote
+                Some('"') => {
+                    field.push('"');
+                    chars.next();
+                }
+                _ => quoted = false,
+            },
+            ('"', false) => quoted = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) => (),
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+            }
+            _ => field.push(c),
+        }
+    }
+    if !field.is_empty() || !record.is_empty() {
+        record.push(field);
+        records.push(record);
+    }
+    // skip blank lines
+    records.retain(|r| r.iter().any(|f| !f.trim().is_empty()));
+    records
+}
+
+/// Writes a record of fields as a line of CSV, quoting fields when needed.
+fn write_csv_row(fields: &[String]) -> String {
+    let mut line = fields
+        .iter()
+        .map(|f| match f.contains([',', '"', '\n']) {
+            true => format!("\"{}\"", f.replace('"', "\"\"")),
+            false => f.clone(),
+        })
+        .collect::<Vec<String>>()
+        .join(",");
+    line.push('\n');
+    line
+}
+
+#[derive(Error, Debug)]
+pub enum BomError {
+    #[error("the BOM is empty")]
+    Empty,
+    #[error("the BOM has no {0} column")]
+    MissingColumn(&'static str),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KICAD_BOM: &str = "\
+\"Reference\",\"Value\",\"Footprint\",\"Tolerance\"
+\"R1,R2,R5\",\"4k7\",\"R_0603\",\"5%\"
+\"R3\",\"4.75k\",\"R_0603\",\"1%\"
+\"R4\",\"4k33\",\"R_0603\",\"\"
+\"C1\",\"100n\",\"C_0603\",\"\"
+\"R6\",\"DNP\",\"R_0603\",\"\"
+";
+
+    #[test]
+    fn ut_parse_csv() {
+        let records = parse_csv("a,\"b,c\",\"say \"\"hi\"\"\"\r\n1,2,3");
+        assert_eq!(
+            records,
+            vec![vec!["a", "b,c", "say \"hi\""], vec!["1", "2", "3"]]
+        );
+    }
+
+    #[test]
+    fn ut_annotate() {
+        let bom = Bom::parse(KICAD_BOM).unwrap();
+        let out = parse_csv(&bom.annotate(dec!(5), &Standard::CLASSIC));
+        assert_eq!(out[0][4..], ["Bands", "SMD Code", "E-Series", "Review"]);
+        assert_eq!(out[1][4..], ["yellow violet red gold", "472", "E6", ""]);
+        assert_eq!(
+            out[2][4..],
+            ["yellow violet green brown brown", "66B", "E96", ""]
+        );
+        assert_eq!(out[3][7], "non-standard value");
+        // other parts are left alone
+        assert_eq!(out[4][4..], ["", "", "", ""]);
+        assert_eq!(out[5][7], "unparsable value");
+    }
+
+    #[test]
+    fn ut_annotate_standard() {
+        let bom = Bom::parse(
+            "Reference,Value,Tolerance
+R1,10k,0.05%
+",
+        )
+        .unwrap();
+        let bands = |standard| parse_csv(&bom.annotate(dec!(5), standard))[1][3].clone();
+        assert_eq!(bands(&Standard::CLASSIC), "brown black black red grey");
+        assert_eq!(
+            bands(&Standard::IEC_60062_2016),
+            "brown black black red orange"
+        );
+    }
+
+    #[test]
+    fn ut_missing_column() {
+        assert!(Bom::parse("Designator,Footprint\nR1,R_0603\n").is_err());
+    }
+}
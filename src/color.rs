@@ -0,0 +1,291 @@
+use crate::band::Band;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Colors each band's paint typically shows in a photo taken under daylight.
+///
+/// Every band is matched against both this color and the color used to draw
+/// it ([Band::rgb]), since metallic and dark paints photograph quite
+/// differently from their ideal colors.
+const PHOTOGRAPHED: [(Band, (u8, u8, u8)); 13] = [
+    (Band::Black, (0x2B, 0x2B, 0x2B)),
+    (Band::Brown, (0x6B, 0x3A, 0x1E)),
+    (Band::Red, (0xC4, 0x2B, 0x1C)),
+    (Band::Orange, (0xE8, 0x73, 0x1A)),
+    (Band::Yellow, (0xE6, 0xE6, 0x20)),
+    (Band::Green, (0x2E, 0x8B, 0x3A)),
+    (Band::Blue, (0x1F, 0x4F, 0xA8)),
+    (Band::Violet, (0x7A, 0x3E, 0x9D)),
+    (Band::Grey, (0x8C, 0x8C, 0x8C)),
+    (Band::White, (0xED, 0xED, 0xED)),
+    (Band::Gold, (0xB8, 0x95, 0x3A)),
+    (Band::Silver, (0xC0, 0xC0, 0xC0)),
+    (Band::Pink, (0xE0, 0x8A, 0xA8)),
+];
+
+/// A color in the sRGB color space.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    /// Converts the color into the CIELAB color space (D65 white point).
+    pub fn lab(&self) -> Lab {
+        let linear = |c: u8| {
+            let c = c as f64 / 255.0;
+            match c <= 0.04045 {
+                true => c / 12.92,
+                false => ((c + 0.055) / 1.055).powf(2.4),
+            }
+        };
+        let (r, g, b) = (linear(self.0), linear(self.1), linear(self.2));
+        // scale each component by the reference white
+        let x = (0.4124564 * r + 0.3575761 * g + 0.1804375 * b) / 0.95047;
+        let y = 0.2126729 * r + 0.7151522 * g + 0.0721750 * b;
+        let z = (0.0193339 * r + 0.1191920 * g + 0.9503041 * b) / 1.08883;
+        let f = |t: f64| {
+            let d: f64 = 6.0 / 29.0;
+            match t > d.powi(3) {
+                true => t.cbrt(),
+                false => t / (3.0 * d * d) + 4.0 / 29.0,
+            }
+        };
+        Lab {
+            l: 116.0 * f(y) - 16.0,
+            a: 500.0 * (f(x) - f(y)),
+            b: 200.0 * (f(y) - f(z)),
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Rgb {
+    fn from(c: (u8, u8, u8)) -> Self {
+        Self(c.0, c.1, c.2)
+    }
+}
+
+impl FromStr for Rgb {
+    type Err = ColorError;
+
+    /// Reads a hex color such as `#964B00`, `964B00`, or `#FA0`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ColorError::InvalidHex(s.to_string());
+        let hex = s.trim().strip_prefix('#').unwrap_or(s.trim());
+        if !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(err());
+        }
+        let channel = |i: usize, len: usize| {
+            let v = u8::from_str_radix(&hex[i * len..(i + 1) * len], 16).map_err(|_| err())?;
+            // expand shorthand digits (ex: 'A' -> 0xAA)
+            Ok(match len {
+                1 => v * 0x11,
+                _ => v,
+            })
+        };
+        match hex.len() {
+            3 => Ok(Self(channel(0, 1)?, channel(1, 1)?, channel(2, 1)?)),
+            6 => Ok(Self(channel(0, 2)?, channel(1, 2)?, channel(2, 2)?)),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Display for Rgb {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "#{:02X}{:02X}{:02X}", self.0, self.1, self.2)
+    }
+}
+
+/// The material of a resistor's body, which decides the color the bands are
+/// printed on.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Body {
+    /// Beige body of carbon film resistors.
+    #[default]
+    Carbon,
+    /// Blue body of metal film resistors.
+    Metal,
+    Custom(Rgb),
+}
+
+impl Body {
+    /// Returns the sRGB color of the body.
+    pub const fn rgb(&self) -> (u8, u8, u8) {
+        match self {
+            Self::Carbon => (0xD9, 0xC2, 0x8F),
+            Self::Metal => (0x7F, 0xB3, 0xE0),
+            Self::Custom(c) => (c.0, c.1, c.2),
+        }
+    }
+}
+
+impl FromStr for Body {
+    type Err = ColorError;
+
+    /// Reads a material (`carbon` or `metal`), its color (`beige` or `blue`),
+    /// or any hex color.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_ref() {
+            "carbon" | "beige" => Ok(Self::Carbon),
+            "metal" | "blue" => Ok(Self::Metal),
+            _ => match Rgb::from_str(s) {
+                Ok(c) => Ok(Self::Custom(c)),
+                Err(_) => Err(ColorError::UnknownBody(s.to_string())),
+            },
+        }
+    }
+}
+
+/// A color in the CIELAB color space.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Lab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Lab {
+    /// Computes the perceptual difference to the `other` color using the
+    /// CIEDE2000 formula, where a difference near 1 is just noticeable.
+    pub fn delta_e(&self, other: &Lab) -> f64 {
+        let (l1, a1, b1) = (self.l, self.a, self.b);
+        let (l2, a2, b2) = (other.l, other.a, other.b);
+        let pow7 = |c: f64| c.powi(7) / (c.powi(7) + 25.0_f64.powi(7));
+
+        let c_bar = ((a1 * a1 + b1 * b1).sqrt() + (a2 * a2 + b2 * b2).sqrt()) / 2.0;
+        let g = 0.5 * (1.0 - pow7(c_bar).sqrt());
+        let (a1, a2) = ((1.0 + g) * a1, (1.0 + g) * a2);
+        let (c1, c2) = ((a1 * a1 + b1 * b1).sqrt(), (a2 * a2 + b2 * b2).sqrt());
+        let hue = |b: f64, a: f64| match a == 0.0 && b == 0.0 {
+            true => 0.0,
+            false => b.atan2(a).to_degrees().rem_euclid(360.0),
+        };
+        let (h1, h2) = (hue(b1, a1), hue(b2, a2));
+
+        let dl = l2 - l1;
+        let dc = c2 - c1;
+        let dh = match c1 * c2 == 0.0 {
+            true => 0.0,
+            false => match h2 - h1 {
+                d if d > 180.0 => d - 360.0,
+                d if d < -180.0 => d + 360.0,
+                d => d,
+            },
+        };
+        let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+        let l_bar = (l1 + l2) / 2.0;
+        let c_bar = (c1 + c2) / 2.0;
+        let h_bar = match (c1 * c2 == 0.0, (h1 - h2).abs() <= 180.0) {
+            (true, _) => h1 + h2,
+            (false, true) => (h1 + h2) / 2.0,
+            (false, false) if h1 + h2 < 360.0 => (h1 + h2 + 360.0) / 2.0,
+            (false, false) => (h1 + h2 - 360.0) / 2.0,
+        };
+        let cos = |deg: f64| deg.to_radians().cos();
+        let t = 1.0 - 0.17 * cos(h_bar - 30.0)
+            + 0.24 * cos(2.0 * h_bar)
+            + 0.32 * cos(3.0 * h_bar + 6.0)
+            - 0.20 * cos(4.0 * h_bar - 63.0);
+        let theta = 30.0 * (-((h_bar - 275.0) / 25.0).powi(2)).exp();
+        let rc = 2.0 * pow7(c_bar).sqrt();
+        let sl = 1.0 + 0.015 * (l_bar - 50.0).powi(2) / (20.0 + (l_bar - 50.0).powi(2)).sqrt();
+        let sc = 1.0 + 0.045 * c_bar;
+        let sh = 1.0 + 0.015 * c_bar * t;
+        let rt = -(2.0 * theta).to_radians().sin() * rc;
+
+        ((dl / sl).powi(2) + (dc / sc).powi(2) + (dh / sh).powi(2) + rt * (dc / sc) * (dh / sh))
+            .sqrt()
+    }
+}
+
+/// Ranks every band by its perceptual difference (CIEDE2000) to `color`, from
+/// closest to farthest.
+pub fn rank(color: Rgb) -> Vec<(Band, f64)> {
+    let lab = color.lab();
+    let mut ranks: Vec<(Band, f64)> = Vec::new();
+    for (band, photographed) in PHOTOGRAPHED.iter() {
+        // keep the closer of the drawn and photographed colors
+        let d = [band.rgb(), *photographed]
+            .iter()
+            .map(|c| lab.delta_e(&Rgb::from(*c).lab()))
+            .fold(f64::INFINITY, f64::min);
+        ranks.push((band.clone(), d));
+    }
+    ranks.sort_by(|(_, x), (_, y)| x.total_cmp(y));
+    ranks
+}
+
+/// Finds the band closest in color to `color`, along with its perceptual
+/// difference (CIEDE2000).
+pub fn nearest(color: Rgb) -> (Band, f64) {
+    rank(color).swap_remove(0)
+}
+
+#[derive(Error, Debug)]
+pub enum ColorError {
+    #[error("invalid hex color {0:?} (expects #RRGGBB)")]
+    InvalidHex(String),
+    #[error("unknown body {0:?} (expects carbon, metal, or a hex color)")]
+    UnknownBody(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::band;
+
+    #[test]
+    fn ut_parse_hex() {
+        assert_eq!(Rgb::from_str("#964B00").unwrap(), Rgb(0x96, 0x4B, 0x00));
+        assert_eq!(Rgb::from_str("fa0").unwrap(), Rgb(0xFF, 0xAA, 0x00));
+        assert!(Rgb::from_str("#964B0").is_err());
+        assert!(Rgb::from_str("#GG0000").is_err());
+    }
+
+    #[test]
+    fn ut_delta_e() {
+        // test data from Sharma, Wu, and Dalal (2005)
+        let x = Lab {
+            l: 50.0,
+            a: 2.6772,
+            b: -79.7751,
+        };
+        let y = Lab {
+            l: 50.0,
+            a: 0.0,
+            b: -82.7485,
+        };
+        assert!((x.delta_e(&y) - 2.0425).abs() < 1e-4);
+        let x = Lab {
+            l: 50.0,
+            a: 2.5,
+            b: 0.0,
+        };
+        let y = Lab {
+            l: 73.0,
+            a: 25.0,
+            b: -18.0,
+        };
+        assert!((x.delta_e(&y) - 27.1492).abs() < 1e-4);
+    }
+
+    #[test]
+    fn ut_nearest() {
+        assert_eq!(nearest(Rgb(0x96, 0x4B, 0x00)).0, Band::Brown);
+        assert_eq!(nearest(Rgb(0xD0, 0x20, 0x20)).0, Band::Red);
+        assert_eq!(nearest(Rgb(0xC0, 0x9A, 0x40)).0, Band::Gold);
+        assert_eq!(nearest(Rgb(0xF4, 0xF4, 0xF0)).0, Band::White);
+        // hex colors are accepted as band input
+        assert_eq!(band::parse("#964B00").unwrap(), Band::Brown);
+        assert!(band::parse("#96").is_err());
+    }
+
+    #[test]
+    fn ut_parse_body() {
+        assert_eq!(Body::from_str("metal").unwrap(), Body::Metal);
+        assert_eq!(Body::from_str("Beige").unwrap(), Body::Carbon);
+        assert_eq!(Body::from_str("#336699").unwrap().rgb(), (0x33, 0x66, 0x99));
+        assert!(Body::from_str("wood").is_err());
+    }
+}
@@ -0,0 +1,348 @@
+use crate::ohm::Precision;
+use crate::resistance::Resistance;
+use rust_decimal_macros::dec;
+use std::collections::HashSet;
+use std::fmt::Display;
+
+/// An arrangement of resistors that together produce a single resistance.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Network {
+    Part(Resistance),
+    Series(Vec<Network>),
+    Parallel(Vec<Network>),
+}
+
+impl Network {
+    /// Computes the equivalent resistance of the network.
+    pub fn value(&self) -> Precision {
+        match self {
+            Self::Part(r) => r.raw(),
+            Self::Series(nets) => nets.iter().map(|n| n.value()).sum(),
+            Self::Parallel(nets) => parallel(nets.iter().map(|n| n.value())),
+        }
+    }
+
+    /// Computes the lowest equivalent resistance allowed by the tolerances of
+    /// the parts.
+    pub fn minimum(&self) -> Precision {
+        match self {
+            Self::Part(r) => r.minimum(),
+            Self::Series(nets) => nets.iter().map(|n| n.minimum()).sum(),
+            Self::Parallel(nets) => parallel(nets.iter().map(|n| n.minimum())),
+        }
+    }
+
+    /// Computes the highest equivalent resistance allowed by the tolerances of
+    /// the parts.
+    pub fn maximum(&self) -> Precision {
+        match self {
+            Self::Part(r) => r.maximum(),
+            Self::Series(nets) => nets.iter().map(|n| n.maximum()).sum(),
+            Self::Parallel(nets) => parallel(nets.iter().map(|n| n.maximum())),
+        }
+    }
+
+    /// Lists every resistor used in the network.
+    pub fn parts(&self) -> Vec<Resistance> {
+        match self {
+            Self::Part(r) => vec![r.clone()],
+            Self::Series(nets) | Self::Parallel(nets) => {
+                nets.iter().flat_map(|n| n.parts()).collect()
+            }
+        }
+    }
+
+    /// Orders the branches of the network so equivalent arrangements are
+    /// written identically.
+    fn normalize(self) -> Self {
+        let sort = |nets: Vec<Network>| {
+            let mut nets: Vec<Network> = nets.into_iter().map(|n| n.normalize()).collect();
+            nets.sort_by_key(|n| n.value());
+            nets
+        };
+        match self {
+            Self::Part(r) => Self::Part(r),
+            Self::Series(nets) => Self::Series(sort(nets)),
+            Self::Parallel(nets) => Self::Parallel(sort(nets)),
+        }
+    }
+}
+
+impl Display for Network {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |nets: &Vec<Network>, sep: &str| {
+            nets.iter()
+                .map(|n| match n {
+                    Self::Part(_) => n.to_string(),
+                    _ => format!("({})", n),
+                })
+                .collect::<Vec<String>>()
+                .join(sep)
+        };
+        match self {
+            Self::Part(r) => write!(f, "{}", crate::value::Value::new(r.raw())),
+            Self::Series(nets) => write!(f, "{}", join(nets, " + ")),
+            Self::Parallel(nets) => write!(f, "{}", join(nets, " || ")),
+        }
+    }
+}
+
+/// Computes the equivalent resistance of `values` placed in parallel.
+///
+/// Branches are combined in pairs as `a*b / (a+b)`, which stays exact for
+/// common cases such as equal resistors.
+fn parallel(values: impl Iterator<Item = Precision>) -> Precision {
+    values
+        .reduce(|a, b| match a + b {
+            // a branch without resistance shorts the network
+            sum if sum.is_zero() => sum,
+            sum => a * b / sum,
+        })
+        .unwrap_or_default()
+}
+
+/// A network that lands near the targeted resistance.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Combination {
+    network: Network,
+    error: Precision,
+}
+
+impl Combination {
+    fn new(network: Network, target: Precision) -> Self {
+        let error = (network.value() - target) / target * dec!(100);
+        Self {
+            network: network.normalize(),
+            error,
+        }
+    }
+
+    pub fn network(&self) -> &Network {
+        &self.network
+    }
+
+    /// Percent error from the targeted resistance.
+    pub fn error(&self) -> Precision {
+        self.error
+    }
+}
+
+/// Maximum number of resistors to combine.
+pub const MAX_PARTS: usize = 3;
+
+/// Searches for networks of at most `max_parts` resistors, each drawn from
+/// `parts`, whose equivalent resistance is within `max_error` percent of
+/// `target`.
+///
+/// The results are ranked by the number of parts and then by the error.
+pub fn search(
+    target: Precision,
+    parts: &[Resistance],
+    max_error: Precision,
+    max_parts: usize,
+) -> Vec<Combination> {
+    let mut parts = parts.to_vec();
+    parts.sort_by(|a, b| {
+        a.raw()
+            .cmp(&b.raw())
+            .then(a.tolerance().cmp(&b.tolerance()))
+    });
+    parts.dedup();
+
+    let mut found: Vec<Combination> = Vec::new();
+    let mut keep = |net: Network| {
+        let combo = Combination::new(net, target);
+        if combo.error.abs() <= max_error {
+            found.push(combo);
+        }
+    };
+    // single parts
+    for r in nearest(&parts, target) {
+        keep(Network::Part(r));
+    }
+    if max_parts >= 2 {
+        for a in &parts {
+            // a + b
+            for b in nearest(&parts, target - a.raw()) {
+                keep(Network::Series(vec![
+                    Network::Part(a.clone()),
+                    Network::Part(b),
+                ]));
+            }
+            // a || b
+            for b in nearest(&parts, reciprocal_diff(target, a.raw())) {
+                keep(Network::Parallel(vec![
+                    Network::Part(a.clone()),
+                    Network::Part(b),
+                ]));
+            }
+        }
+    }
+    if max_parts >= 3 {
+        for a in &parts {
+            for b in &parts {
+                // a + (b || c)
+                for c in nearest(&parts, reciprocal_diff(target - a.raw(), b.raw())) {
+                    keep(Network::Series(vec![
+                        Network::Part(a.clone()),
+                        Network::Parallel(vec![Network::Part(b.clone()), Network::Part(c)]),
+                    ]));
+                }
+                // a || (b + c)
+                for c in nearest(&parts, reciprocal_diff(target, a.raw()) - b.raw()) {
+                    keep(Network::Parallel(vec![
+                        Network::Part(a.clone()),
+                        Network::Series(vec![Network::Part(b.clone()), Network::Part(c)]),
+                    ]));
+                }
+                // the remaining arrangements are symmetric in `a` and `b`
+                if b.raw() < a.raw() {
+                    continue;
+                }
+                // a + b + c
+                for c in nearest(&parts, target - a.raw() - b.raw()) {
+                    keep(Network::Series(vec![
+                        Network::Part(a.clone()),
+                        Network::Part(b.clone()),
+                        Network::Part(c),
+                    ]));
+                }
+                // a || b || c
+                for c in nearest(
+                    &parts,
+                    reciprocal_diff(reciprocal_diff(target, a.raw()), b.raw()),
+                ) {
+                    keep(Network::Parallel(vec![
+                        Network::Part(a.clone()),
+                        Network::Part(b.clone()),
+                        Network::Part(c),
+                    ]));
+                }
+            }
+        }
+    }
+    // rank by the number of parts and then the error, counting the parts once
+    found.sort_by_cached_key(|c| (c.network.parts().len(), c.error.abs()));
+    // remove arrangements that were discovered more than once
+    let mut seen = HashSet::new();
+    found.retain(|c| seen.insert(format!("{:?}", c.network)));
+    found
+}
+
+/// Computes the resistance that, placed in parallel with `r`, yields `total`.
+///
+/// Returns a non-positive value when no such resistance exists.
+fn reciprocal_diff(total: Precision, r: Precision) -> Precision {
+    match total > Precision::ZERO && r > total {
+        // equivalent to 1 / (1/total - 1/r) without inexact reciprocals
+        true => total * r / (r - total),
+        false => dec!(-1),
+    }
+}
+
+/// Finds the parts in the sorted list `parts` whose values are immediately
+/// below and above the `ideal` value.
+fn nearest(parts: &[Resistance], ideal: Precision) -> Vec<Resistance> {
+    if ideal <= Precision::ZERO {
+        return Vec::new();
+    }
+    let i = parts.partition_point(|p| p.raw() < ideal);
+    // include every tolerance available at the neighboring values, which sit
+    // in runs of equal values on either side of `i`
+    let lo = match i.checked_sub(1) {
+        Some(j) => parts[..i].partition_point(|p| p.raw() < parts[j].raw()),
+        None => i,
+    };
+    let hi = match parts.get(i) {
+        Some(above) => i + parts[i..].partition_point(|p| p.raw() <= above.raw()),
+        None => i,
+    };
+    parts[lo..hi].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn part(raw: Precision) -> Resistance {
+        Resistance::new(raw, dec!(1), None)
+    }
+
+    #[test]
+    fn ut_nearest() {
+        let parts = [
+            part(dec!(100)),
+            Resistance::new(dec!(100), dec!(5), None),
+            part(dec!(220)),
+            part(dec!(470)),
+            Resistance::new(dec!(470), dec!(5), None),
+            part(dec!(1000)),
+        ];
+        let values = |found: Vec<Resistance>| -> Vec<(Precision, Precision)> {
+            found.iter().map(|p| (p.raw(), p.tolerance())).collect()
+        };
+        assert_eq!(
+            values(nearest(&parts, dec!(300))),
+            vec![
+                (dec!(220), dec!(1)),
+                (dec!(470), dec!(1)),
+                (dec!(470), dec!(5))
+            ]
+        );
+        // an exact value is its own neighbor above
+        assert_eq!(
+            values(nearest(&parts, dec!(100))),
+            vec![(dec!(100), dec!(1)), (dec!(100), dec!(5))]
+        );
+        assert_eq!(
+            values(nearest(&parts, dec!(2000))),
+            vec![(dec!(1000), dec!(1))]
+        );
+        assert!(nearest(&parts, dec!(-1)).is_empty());
+    }
+
+    #[test]
+    fn ut_network_value() {
+        let net = Network::Series(vec![
+            Network::Part(part(dec!(100))),
+            Network::Parallel(vec![
+                Network::Part(part(dec!(200))),
+                Network::Part(part(dec!(200))),
+            ]),
+        ]);
+        assert_eq!(net.value(), dec!(200));
+        assert_eq!(net.minimum(), dec!(198));
+        assert_eq!(net.maximum(), dec!(202));
+        assert_eq!(net.parts().len(), 3);
+        assert_eq!(net.to_string(), "100 Ω + (200 Ω || 200 Ω)");
+    }
+
+    #[test]
+    fn ut_search_prefers_fewer_parts() {
+        let parts = [
+            part(dec!(100)),
+            part(dec!(220)),
+            part(dec!(470)),
+            part(dec!(1000)),
+        ];
+        let found = search(dec!(570), &parts, dec!(0.5), MAX_PARTS);
+        assert_eq!(
+            found.first().unwrap().network(),
+            &Network::Series(vec![
+                Network::Part(part(dec!(100))),
+                Network::Part(part(dec!(470)))
+            ])
+        );
+        assert!(found.iter().all(|c| c.error().abs() <= dec!(0.5)));
+    }
+
+    #[test]
+    fn ut_search_exact_part() {
+        let parts = [part(dec!(100)), part(dec!(220)), part(dec!(470))];
+        let found = search(dec!(220), &parts, dec!(0), 1);
+        assert_eq!(
+            found,
+            vec![Combination::new(Network::Part(part(dec!(220))), dec!(220))]
+        );
+    }
+}
@@ -0,0 +1,324 @@
+use crate::alias::Alias;
+use crate::band::{self, EncodeError, FromBand, TempCoeff, Tolerance};
+use crate::eseries::ESeries;
+use crate::locale::Locale;
+use crate::term::ColorSupport;
+use crate::value::{self, Percent};
+use crate::Standard;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+use toml::{Table, Value};
+
+/// Environment variable to override the location of the configuration file.
+pub const CONFIG_VAR: &str = "OHM_CONFIG";
+
+/// How bands are identified when no flag asks for a particular style.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Format {
+    /// Colored bands on the resistor's body.
+    Bands,
+    /// Names of the colors.
+    Names,
+    /// IEC 60757 abbreviations of the colors.
+    Iec,
+    /// Symbols and codes along with the role of each band.
+    Accessible,
+}
+
+impl FromStr for Format {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "bands" => Ok(Self::Bands),
+            "names" => Ok(Self::Names),
+            "iec" => Ok(Self::Iec),
+            "accessible" => Ok(Self::Accessible),
+            _ => Err(ConfigError::InvalidValue(
+                String::from("format"),
+                String::from("expects bands, names, iec, or accessible"),
+            )),
+        }
+    }
+}
+
+/// Persistent defaults for options that are not given on the command-line.
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Config {
+    pub format: Option<Format>,
+    pub color: Option<ColorSupport>,
+    pub lang: Option<Locale>,
+    pub series: Option<ESeries>,
+    /// Tables that give the values of the tolerance and temperature
+    /// coefficient bands.
+    pub standard: Option<Standard>,
+    /// Tolerance of a resistor without a tolerance band.
+    pub three_band_tol: Option<Percent>,
+    /// Tolerance of a part given by its value, such as an SMD part.
+    pub smd_tol: Option<Percent>,
+    /// Extra names for bands.
+    pub aliases: Vec<Alias>,
+}
+
+impl Config {
+    /// Determines the configuration file to use.
+    pub fn default_path() -> Option<PathBuf> {
+        match std::env::var_os(CONFIG_VAR) {
+            Some(p) => Some(PathBuf::from(p)),
+            None => {
+                let config = match std::env::var_os("XDG_CONFIG_HOME") {
+                    Some(p) => PathBuf::from(p),
+                    None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+                };
+                Some(config.join("ohm").join("config.toml"))
+            }
+        }
+    }
+
+    /// Reads the configuration from the default file. A missing file sets no
+    /// defaults.
+    pub fn load() -> Result<Self, ConfigError> {
+        match Self::default_path() {
+            Some(path) => Self::load_from(&path),
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Reads the configuration from `path`. A missing file sets no defaults.
+    pub fn load_from(path: &Path) -> Result<Self, ConfigError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        Self::from_str(&std::fs::read_to_string(path)?)
+    }
+}
+
+impl FromStr for Config {
+    type Err = ConfigError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let table: Table = s.parse()?;
+        let mut config = Self::default();
+        for (key, value) in &table {
+            match key.as_ref() {
+                "format" => config.format = Some(setting(key, value)?),
+                "color" => {
+                    // the terminal decides the colors unless told otherwise
+                    config.color = match text(key, value)?.as_ref() {
+                        "auto" => None,
+                        _ => Some(setting(key, value)?),
+                    }
+                }
+                "lang" => config.lang = Some(setting(key, value)?),
+                "series" => config.series = Some(setting(key, value)?),
+                "standard" => {
+                    config.standard = Some(match value.as_table() {
+                        Some(t) => custom_standard(key, t)?,
+                        None => setting(key, value)?,
+                    })
+                }
+                "tolerance" => {
+                    for (name, value) in section(key, value)? {
+                        let key = format!("{}.{}", key, name);
+                        match name.as_ref() {
+                            "3-band" => config.three_band_tol = Some(setting(&key, value)?),
+                            "smd" => config.smd_tol = Some(setting(&key, value)?),
+                            _ => return Err(ConfigError::UnknownKey(key)),
+                        }
+                    }
+                }
+                "aliases" => {
+                    for (name, value) in section(key, value)? {
+                        let key = format!("{}.{}", key, name);
+                        let invalid =
+                            |e: &dyn Display| ConfigError::InvalidValue(key.clone(), e.to_string());
+                        let band = band::parse(&text(&key, value)?).map_err(|e| invalid(&e))?;
+                        let alias = Alias::new(name, band).map_err(|e| invalid(&e))?;
+                        config.aliases.push(alias);
+                    }
+                }
+                _ => return Err(ConfigError::UnknownKey(key.to_string())),
+            }
+        }
+        Ok(config)
+    }
+}
+
+/// Builds the tables of a `[standard]` section, which starts from the `base`
+/// preset (classic by default) and replaces the values of single bands.
+fn custom_standard(key: &str, table: &Table) -> Result<Standard, ConfigError> {
+    let mut standard = match table.get("base") {
+        Some(v) => setting::<Standard>(&format!("{}.base", key), v)?,
+        None => Standard::default(),
+    }
+    .renamed("custom", "custom color code");
+    for (name, value) in table {
+        let key = format!("{}.{}", key, name);
+        match name.as_ref() {
+            "base" => (),
+            "tolerance" => {
+                for (color, value) in section(&key, value)? {
+                    let key = format!("{}.{}", key, color);
+                    let invalid =
+                        |e: &dyn Display| ConfigError::InvalidValue(key.clone(), e.to_string());
+                    let tol = band::parse(color)
+                        .and_then(|b| Tolerance::from_band(&b))
+                        .map_err(|e| invalid(&e))?;
+                    let percent = setting::<Percent>(&key, value)?.value();
+                    let ppm = value::ppm(percent)
+                        .ok_or_else(|| invalid(&EncodeError::Tolerance(percent)))?;
+                    standard = standard.with_tolerance(tol, ppm);
+                }
+            }
+            "temp-coeff" => {
+                for (color, value) in section(&key, value)? {
+                    let key = format!("{}.{}", key, color);
+                    let tc = band::parse(color)
+                        .and_then(|b| TempCoeff::from_band(&b))
+                        .map_err(|e| ConfigError::InvalidValue(key.clone(), e.to_string()))?;
+                    standard = standard.with_temp_coeff(tc, setting(&key, value)?);
+                }
+            }
+            _ => return Err(ConfigError::UnknownKey(key)),
+        }
+    }
+    Ok(standard)
+}
+
+/// Reads the `value` of `key` as text, allowing numbers to be written without
+/// quotes.
+fn text(key: &str, value: &Value) -> Result<String, ConfigError> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Integer(n) => Ok(n.to_string()),
+        Value::Float(n) => Ok(n.to_string()),
+        _ => Err(ConfigError::InvalidValue(
+            key.to_string(),
+            String::from("expects a string or number"),
+        )),
+    }
+}
+
+/// Reads the `value` of `key` as a setting of type `T`.
+fn setting<T>(key: &str, value: &Value) -> Result<T, ConfigError>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    text(key, value)?
+        .parse()
+        .map_err(|e: T::Err| ConfigError::InvalidValue(key.to_string(), e.to_string()))
+}
+
+/// Reads the `value` of `key` as a table of settings.
+fn section<'a>(key: &str, value: &'a Value) -> Result<&'a Table, ConfigError> {
+    value.as_table().ok_or(ConfigError::InvalidValue(
+        key.to_string(),
+        String::from("expects a table"),
+    ))
+}
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("invalid setting {0:?} in the configuration file: {1}")]
+    InvalidValue(String, String),
+    #[error("unknown setting {0:?} in the configuration file (hint: set {CONFIG_VAR} to use another file)")]
+    UnknownKey(String),
+    #[error("malformed configuration file: {0}")]
+    Syntax(#[from] toml::de::Error),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::band::Band;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn ut_parse_config() {
+        let config = Config::from_str(
+            r#"
+format = "iec"
+color = 256
+lang = "de"
+series = "E96"
+standard = "iec60062-2016"
+
+[tolerance]
+3-band = "20%"
+smd = 1
+
+[aliases]
+AU = "gold"
+"#,
+        )
+        .unwrap();
+        assert_eq!(config.format, Some(Format::Iec));
+        assert_eq!(config.color, Some(ColorSupport::Ansi256));
+        assert_eq!(config.lang, Some(Locale::De));
+        assert_eq!(config.series, Some(ESeries::E96));
+        assert_eq!(config.standard, Some(Standard::IEC_60062_2016));
+        assert_eq!(config.three_band_tol.unwrap().value(), dec!(20));
+        assert_eq!(config.smd_tol.unwrap().value(), dec!(1));
+        assert_eq!(config.aliases, vec![Alias::new("au", Band::Gold).unwrap()]);
+        // an empty file sets no defaults
+        assert_eq!(Config::from_str("").unwrap(), Config::default());
+        assert_eq!(Config::from_str("color = \"auto\"").unwrap().color, None);
+    }
+
+    #[test]
+    fn ut_custom_standard() {
+        let config = Config::from_str(
+            r#"
+[standard]
+base = "iec60062-2016"
+tolerance = { gold = "4%" }
+temp-coeff = { orange = 25, yellow = 15 }
+"#,
+        )
+        .unwrap();
+        let standard = config.standard.unwrap();
+        assert_eq!(standard.to_string(), "custom color code");
+        assert_eq!(standard.temp_coeff(TempCoeff::Orange).unwrap(), 25);
+        assert_eq!(standard.temp_coeff(TempCoeff::Yellow).unwrap(), 15);
+        assert_eq!(standard.temp_coeff(TempCoeff::Red).unwrap(), 50);
+        assert_eq!(
+            standard.tolerance(Tolerance::Gold).unwrap(),
+            ohm_core::Ppm(40_000)
+        );
+        // the rest of the tables come from the base
+        assert_eq!(
+            standard.tolerance(Tolerance::Grey).unwrap(),
+            ohm_core::Ppm(100)
+        );
+    }
+
+    #[test]
+    fn ut_invalid_config() {
+        assert!(matches!(
+            Config::from_str("colour = \"never\""),
+            Err(ConfigError::UnknownKey(_))
+        ));
+        assert!(matches!(
+            Config::from_str("[tolerance]\nsmd = \"loose\""),
+            Err(ConfigError::InvalidValue(k, _)) if k == "tolerance.smd"
+        ));
+        assert!(matches!(
+            Config::from_str("[standard.temp-coeff]\ngold = 5"),
+            Err(ConfigError::InvalidValue(k, _)) if k == "standard.temp-coeff.gold"
+        ));
+        assert!(matches!(
+            Config::from_str("format = "),
+            Err(ConfigError::Syntax(_))
+        ));
+        // aliases cannot take over the built-in names
+        assert!(matches!(
+            Config::from_str("[aliases]\nk = \"brown\""),
+            Err(ConfigError::InvalidValue(k, _)) if k == "aliases.k"
+        ));
+    }
+}
@@ -0,0 +1,214 @@
+use crate::ohm::Precision;
+use crate::value;
+use rust_decimal_macros::dec;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ESeries {
+    E6,
+    E12,
+    E24,
+    E48,
+    E96,
+    E192,
+}
+
+const E6: [u16; 6] = [10, 15, 22, 33, 47, 68];
+
+const E12: [u16; 12] = [10, 12, 15, 18, 22, 27, 33, 39, 47, 56, 68, 82];
+
+const E24: [u16; 24] = [
+    10, 11, 12, 13, 15, 16, 18, 20, 22, 24, 27, 30, 33, 36, 39, 43, 47, 51, 56, 62, 68, 75, 82, 91,
+];
+
+const E48: [u16; 48] = [
+    100, 105, 110, 115, 121, 127, 133, 140, 147, 154, 162, 169, 178, 187, 196, 205, 215, 226, 237,
+    249, 261, 274, 287, 301, 316, 332, 348, 365, 383, 402, 422, 442, 464, 487, 511, 536, 562, 590,
+    619, 649, 681, 715, 750, 787, 825, 866, 909, 953,
+];
+
+const E96: [u16; 96] = [
+    100, 102, 105, 107, 110, 113, 115, 118, 121, 124, 127, 130, 133, 137, 140, 143, 147, 150, 154,
+    158, 162, 165, 169, 174, 178, 182, 187, 191, 196, 200, 205, 210, 215, 221, 226, 232, 237, 243,
+    249, 255, 261, 267, 274, 280, 287, 294, 301, 309, 316, 324, 332, 340, 348, 357, 365, 374, 383,
+    392, 402, 412, 422, 432, 442, 453, 464, 475, 487, 499, 511, 523, 536, 549, 562, 576, 590, 604,
+    619, 634, 649, 665, 681, 698, 715, 732, 750, 768, 787, 806, 825, 845, 866, 887, 909, 931, 953,
+    976,
+];
+
+const E192: [u16; 192] = [
+    100, 101, 102, 104, 105, 106, 107, 109, 110, 111, 113, 114, 115, 117, 118, 120, 121, 123, 124,
+    126, 127, 129, 130, 132, 133, 135, 137, 138, 140, 142, 143, 145, 147, 149, 150, 152, 154, 156,
+    158, 160, 162, 164, 165, 167, 169, 172, 174, 176, 178, 180, 182, 184, 187, 189, 191, 193, 196,
+    198, 200, 203, 205, 208, 210, 213, 215, 218, 221, 223, 226, 229, 232, 234, 237, 240, 243, 246,
+    249, 252, 255, 258, 261, 264, 267, 271, 274, 277, 280, 284, 287, 291, 294, 298, 301, 305, 309,
+    312, 316, 320, 324, 328, 332, 336, 340, 344, 348, 352, 357, 361, 365, 370, 374, 379, 383, 388,
+    392, 397, 402, 407, 412, 417, 422, 427, 432, 437, 442, 448, 453, 459, 464, 470, 475, 481, 487,
+    493, 499, 505, 511, 517, 523, 530, 536, 542, 549, 556, 562, 569, 576, 583, 590, 597, 604, 612,
+    619, 626, 634, 642, 649, 657, 665, 673, 681, 690, 698, 706, 715, 723, 732, 741, 750, 759, 768,
+    777, 787, 796, 806, 816, 825, 835, 845, 856, 866, 876, 887, 898, 909, 920, 931, 942, 953, 965,
+    976, 988,
+];
+
+/// Lowest decade (as a power of ten) searched for standard values.
+const MIN_DECADE: i32 = -2;
+
+/// Highest decade (as a power of ten) searched for standard values.
+const MAX_DECADE: i32 = 9;
+
+impl ESeries {
+    /// Lists every series from the most to least common.
+    pub const ALL: [ESeries; 6] = [
+        Self::E6,
+        Self::E12,
+        Self::E24,
+        Self::E48,
+        Self::E96,
+        Self::E192,
+    ];
+
+    /// Returns the significands of the series for a single decade.
+    pub fn significands(&self) -> &'static [u16] {
+        match self {
+            Self::E6 => &E6,
+            Self::E12 => &E12,
+            Self::E24 => &E24,
+            Self::E48 => &E48,
+            Self::E96 => &E96,
+            Self::E192 => &E192,
+        }
+    }
+
+    /// Number of significant digits used to write a value from the series.
+    pub fn digits(&self) -> usize {
+        match self {
+            Self::E6 | Self::E12 | Self::E24 => 2,
+            Self::E48 | Self::E96 | Self::E192 => 3,
+        }
+    }
+
+    /// The tolerance (in percent) a part from this series is typically
+    /// manufactured to.
+    pub fn tolerance(&self) -> Precision {
+        match self {
+            Self::E6 => dec!(20),
+            Self::E12 => dec!(10),
+            Self::E24 => dec!(5),
+            Self::E48 => dec!(2),
+            Self::E96 => dec!(1),
+            Self::E192 => dec!(0.5),
+        }
+    }
+
+    /// Lists every value of the series between `lo` and `hi` (inclusive) in
+    /// ascending order.
+    pub fn between(&self, lo: Precision, hi: Precision) -> Vec<Precision> {
+        let mut values = Vec::new();
+        for decade in MIN_DECADE..=MAX_DECADE {
+            for sig in self.significands() {
+                let value = value::scale((*sig).into(), decade - (self.digits() as i32 - 1));
+                if value >= lo && value <= hi {
+                    values.push(value);
+                }
+            }
+        }
+        values
+    }
+
+    /// Checks if `value` is exactly one of the series' standard values.
+    pub fn contains(&self, value: Precision) -> bool {
+        match value::significand(value, self.digits() as u32) {
+            Some((sig, _)) => self.significands().iter().any(|s| *s as u32 == sig),
+            None => false,
+        }
+    }
+
+    /// Finds the most common series that includes `value`.
+    pub fn smallest_containing(value: Precision) -> Option<ESeries> {
+        Self::ALL.into_iter().find(|s| s.contains(value))
+    }
+}
+
+impl Display for ESeries {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::E6 => "E6",
+                Self::E12 => "E12",
+                Self::E24 => "E24",
+                Self::E48 => "E48",
+                Self::E96 => "E96",
+                Self::E192 => "E192",
+            }
+        )
+    }
+}
+
+impl FromStr for ESeries {
+    type Err = ESeriesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let lower = s.to_ascii_lowercase();
+        Ok(match lower.strip_prefix('e').unwrap_or(&lower) {
+            "6" => Self::E6,
+            "12" => Self::E12,
+            "24" => Self::E24,
+            "48" => Self::E48,
+            "96" => Self::E96,
+            "192" => Self::E192,
+            _ => return Err(ESeriesError::Unknown(s.to_string())),
+        })
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ESeriesError {
+    #[error("unknown E-series {0:?} (expects E6, E12, E24, E48, E96, or E192)")]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn ut_series_lengths() {
+        assert_eq!(ESeries::E48.significands().len(), 48);
+        assert_eq!(ESeries::E96.significands().len(), 96);
+        assert_eq!(ESeries::E192.significands().len(), 192);
+        // every E96 value is also an E192 value
+        assert!(E96.iter().all(|v| E192.contains(v)));
+    }
+
+    #[test]
+    fn ut_contains() {
+        assert!(ESeries::E24.contains(dec!(4700)));
+        assert!(ESeries::E24.contains(dec!(0.47)));
+        assert!(!ESeries::E12.contains(dec!(5100)));
+        assert!(ESeries::E96.contains(dec!(10_200)));
+        assert_eq!(ESeries::smallest_containing(dec!(6800)), Some(ESeries::E6));
+        assert_eq!(ESeries::smallest_containing(dec!(4750)), Some(ESeries::E96));
+        assert_eq!(ESeries::smallest_containing(dec!(4321)), None);
+    }
+
+    #[test]
+    fn ut_between() {
+        assert_eq!(
+            ESeries::E6.between(dec!(100), dec!(1000)),
+            vec![
+                dec!(100),
+                dec!(150),
+                dec!(220),
+                dec!(330),
+                dec!(470),
+                dec!(680),
+                dec!(1000)
+            ]
+        );
+    }
+}
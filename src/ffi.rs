@@ -0,0 +1,226 @@
+use crate::band::{self, Band, BandError};
+use crate::ohm::Precision;
+use crate::resistance::Resistance;
+use crate::value::{Percent, Value};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use std::ffi::{c_char, CStr};
+
+/// Default tolerance (in percent) of a part given by its value.
+const DEFAULT_TOLERANCE: Precision = dec!(5);
+
+/// Colors of the bands.
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OhmBand {
+    Black,
+    Brown,
+    Red,
+    Orange,
+    Yellow,
+    Green,
+    Blue,
+    Violet,
+    Grey,
+    White,
+    Gold,
+    Silver,
+    Pink,
+}
+
+impl OhmBand {
+    /// Every band, indexed by its value in C.
+    const ALL: [Self; 13] = [
+        Self::Black,
+        Self::Brown,
+        Self::Red,
+        Self::Orange,
+        Self::Yellow,
+        Self::Green,
+        Self::Blue,
+        Self::Violet,
+        Self::Grey,
+        Self::White,
+        Self::Gold,
+        Self::Silver,
+        Self::Pink,
+    ];
+}
+
+impl TryFrom<u32> for OhmBand {
+    type Error = OhmStatus;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        usize::try_from(value)
+            .ok()
+            .and_then(|i| Self::ALL.get(i).copied())
+            .ok_or(OhmStatus::InvalidBand)
+    }
+}
+
+impl From<OhmBand> for Band {
+    fn from(b: OhmBand) -> Self {
+        match b {
+            OhmBand::Black => Self::Black,
+            OhmBand::Brown => Self::Brown,
+            OhmBand::Red => Self::Red,
+            OhmBand::Orange => Self::Orange,
+            OhmBand::Yellow => Self::Yellow,
+            OhmBand::Green => Self::Green,
+            OhmBand::Blue => Self::Blue,
+            OhmBand::Violet => Self::Violet,
+            OhmBand::Grey => Self::Grey,
+            OhmBand::White => Self::White,
+            OhmBand::Gold => Self::Gold,
+            OhmBand::Silver => Self::Silver,
+            OhmBand::Pink => Self::Pink,
+        }
+    }
+}
+
+/// Outcome of a call to the C API.
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum OhmStatus {
+    Ok,
+    InvalidCode,
+    OutOfRange,
+    UnsupportedBand,
+    ReversedBandOrder,
+    Unrepresentable,
+    /// The text is not a resistance or tolerance.
+    InvalidValue,
+    /// A required pointer is null.
+    NullPointer,
+    /// A band is not one of the [OhmBand] values.
+    InvalidBand,
+}
+
+impl From<BandError> for OhmStatus {
+    fn from(e: BandError) -> Self {
+        match e {
+            BandError::InvalidCode => Self::InvalidCode,
+            BandError::OutOfRange(_) => Self::OutOfRange,
+            BandError::UnsupportedBand(_, _) => Self::UnsupportedBand,
+            BandError::ReversedBandOrder(_, _) => Self::ReversedBandOrder,
+            BandError::Unrepresentable(_) => Self::Unrepresentable,
+        }
+    }
+}
+
+/// A resistance along with the range allowed by its tolerance.
+#[repr(C)]
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct OhmResistance {
+    /// Ideal value in Ohms.
+    pub nominal: f64,
+    /// Lowest value in Ohms allowed by the tolerance.
+    pub min: f64,
+    /// Highest value in Ohms allowed by the tolerance.
+    pub max: f64,
+    /// Allowed tolerance as a percentage.
+    pub tolerance: f64,
+    /// Temperature coefficient in ppm/K, or 0 when `has_tempco` is false.
+    pub tempco: u32,
+    /// Whether the temperature coefficient is marked.
+    pub has_tempco: bool,
+}
+
+impl From<&Resistance> for OhmResistance {
+    fn from(r: &Resistance) -> Self {
+        let float = |d: Precision| d.to_f64().unwrap_or(f64::NAN);
+        Self {
+            nominal: float(r.raw()),
+            min: float(r.minimum()),
+            max: float(r.maximum()),
+            tolerance: float(r.tolerance()),
+            tempco: r.temp_coeff().map_or(0, u32::from),
+            has_tempco: r.temp_coeff().is_some(),
+        }
+    }
+}
+
+/// Reads the `len` bands at `bands`, listed from left to right, into `out`.
+///
+/// # Safety
+///
+/// `bands` must point to `len` readable values and `out` must point to
+/// writable memory for an [OhmResistance]. Values that are not an [OhmBand]
+/// are rejected with [OhmStatus::InvalidBand].
+#[no_mangle]
+pub unsafe extern "C" fn ohm_decode_bands(
+    bands: *const u32,
+    len: usize,
+    out: *mut OhmResistance,
+) -> OhmStatus {
+    if bands.is_null() || out.is_null() {
+        return OhmStatus::NullPointer;
+    }
+    let bands = match std::slice::from_raw_parts(bands, len)
+        .iter()
+        .map(|b| OhmBand::try_from(*b).map(Band::from))
+        .collect::<Result<Vec<Band>, OhmStatus>>()
+    {
+        Ok(b) => b,
+        Err(e) => return e,
+    };
+    match band::read(&bands) {
+        Ok(r) => {
+            *out = OhmResistance::from(&Resistance::from(r.resistance()));
+            OhmStatus::Ok
+        }
+        Err(e) => e.into(),
+    }
+}
+
+/// Reads the resistance written as `value` (ex: `4k7`) into `out`, with the
+/// `tolerance` (ex: `1%`) or 5% when `tolerance` is null.
+///
+/// # Safety
+///
+/// `value` and any `tolerance` must be NUL-terminated strings and `out` must
+/// point to writable memory for an [OhmResistance].
+#[no_mangle]
+pub unsafe extern "C" fn ohm_decode_value(
+    value: *const c_char,
+    tolerance: *const c_char,
+    out: *mut OhmResistance,
+) -> OhmStatus {
+    if value.is_null() || out.is_null() {
+        return OhmStatus::NullPointer;
+    }
+    let text = |s: *const c_char| CStr::from_ptr(s).to_str().ok();
+    let value = match text(value).and_then(|s| s.parse::<Value>().ok()) {
+        Some(v) => v,
+        None => return OhmStatus::InvalidValue,
+    };
+    let tolerance = match tolerance.is_null() {
+        true => Some(DEFAULT_TOLERANCE),
+        false => text(tolerance).and_then(|s| s.parse::<Percent>().ok().map(|p| p.value())),
+    };
+    match tolerance {
+        Some(t) => {
+            *out = OhmResistance::from(&Resistance::new(value.ohms(), t, None));
+            OhmStatus::Ok
+        }
+        None => OhmStatus::InvalidValue,
+    }
+}
+
+/// Describes the `status` as a NUL-terminated string that lives for the
+/// duration of the program.
+#[no_mangle]
+pub extern "C" fn ohm_status_message(status: OhmStatus) -> *const c_char {
+    let message = match status {
+        OhmStatus::Ok => c"ok",
+        OhmStatus::InvalidCode => c"invalid color code",
+        OhmStatus::OutOfRange => c"color code requires 3 to 6 values",
+        OhmStatus::UnsupportedBand => c"band is not allowed in its position",
+        OhmStatus::ReversedBandOrder => c"bands are in reverse order",
+        OhmStatus::Unrepresentable => c"cannot be represented with color bands",
+        OhmStatus::InvalidValue => c"invalid resistance or tolerance",
+        OhmStatus::NullPointer => c"unexpected null pointer",
+        OhmStatus::InvalidBand => c"invalid band value",
+    };
+    message.as_ptr()
+}
@@ -0,0 +1,119 @@
+use crate::band::Band;
+use crate::locale::Locale;
+
+pub enum BandGroup {
+    // 3-band: -[|||    ]-
+    R3(Band, Band, Band),
+    // 4-band: -[|||  | ]-
+    R4(Band, Band, Band, Band),
+    // 5-band: -[|||| | ]-
+    R5(Band, Band, Band, Band, Band),
+    // 6-band: -[|||| ||]-
+    R6(Band, Band, Band, Band, Band, Band),
+}
+
+impl From<Vec<Band>> for BandGroup {
+    fn from(vec: Vec<Band>) -> Self {
+        let mut vec = vec;
+        vec.reverse();
+        match vec.len() {
+            3 => Self::R3(vec.pop().unwrap(), vec.pop().unwrap(), vec.pop().unwrap()),
+            4 => Self::R4(
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+            ),
+            5 => Self::R5(
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+            ),
+            6 => Self::R6(
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+                vec.pop().unwrap(),
+            ),
+            _ => panic!("unsupported band length {}", vec.len()),
+        }
+    }
+}
+
+impl BandGroup {
+    /// Places the bands into the 7 evenly spaced slots of the resistor body,
+    /// leaving empty slots to separate the tolerance bands.
+    pub fn layout(&self) -> [Option<&Band>; 7] {
+        match self {
+            Self::R3(b0, b1, b2) => [Some(b0), Some(b1), Some(b2), None, None, None, None],
+            Self::R4(b0, b1, b2, b3) => [Some(b0), Some(b1), Some(b2), None, None, Some(b3), None],
+            Self::R5(b0, b1, b2, b3, b4) => {
+                [Some(b0), Some(b1), Some(b2), Some(b3), None, Some(b4), None]
+            }
+            Self::R6(b0, b1, b2, b3, b4, b5) => [
+                Some(b0),
+                Some(b1),
+                Some(b2),
+                Some(b3),
+                None,
+                Some(b4),
+                Some(b5),
+            ],
+        }
+    }
+
+    pub fn ascii(&self) -> String {
+        self.labeled(|b| b.ascii().to_string())
+    }
+
+    /// Writes the bands using their color names in the given language.
+    pub fn localized(&self, locale: Locale) -> String {
+        self.labeled(|b| locale.name(b).to_string())
+    }
+
+    /// Writes the bands using the label given to each band by `name`.
+    pub fn labeled(&self, name: impl Fn(&Band) -> String) -> String {
+        format!("-[{}]-", self.arranged(",", name))
+    }
+
+    /// Writes the bands as drawn by `draw` on a body that is painted by `fill`,
+    /// leaving a space of the body between neighboring bands.
+    pub fn on_body(&self, draw: impl Fn(&Band) -> String, fill: impl Fn(&str) -> String) -> String {
+        format!("-[{}]-", fill(&self.arranged(" ", draw)))
+    }
+
+    /// Places the bands as written by `draw` in their positions on the body,
+    /// with `sep` between bands that are grouped together.
+    fn arranged(&self, sep: &str, draw: impl Fn(&Band) -> String) -> String {
+        let d = draw;
+        match self {
+            Self::R3(b0, b1, b2) => format!("{}{s}{}{s}{}    ", d(b0), d(b1), d(b2), s = sep),
+            Self::R4(b0, b1, b2, b3) => {
+                format!("{}{s}{}{s}{}  {} ", d(b0), d(b1), d(b2), d(b3), s = sep)
+            }
+            Self::R5(b0, b1, b2, b3, b4) => format!(
+                "{}{s}{}{s}{}{s}{} {} ",
+                d(b0),
+                d(b1),
+                d(b2),
+                d(b3),
+                d(b4),
+                s = sep
+            ),
+            Self::R6(b0, b1, b2, b3, b4, b5) => format!(
+                "{}{s}{}{s}{}{s}{} {}{s}{}",
+                d(b0),
+                d(b1),
+                d(b2),
+                d(b3),
+                d(b4),
+                d(b5),
+                s = sep
+            ),
+        }
+    }
+}
@@ -0,0 +1,247 @@
+use crate::ohm::Precision;
+use crate::resistance::Resistance;
+use crate::value::{Value, ValueRange};
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+/// Environment variable to override the location of the inventory file.
+pub const INVENTORY_VAR: &str = "OHM_INVENTORY";
+
+/// Column names written at the top of an inventory file.
+const HEADER: &str = "value,tolerance,quantity";
+
+/// A number of identical parts on hand.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Stock {
+    part: Resistance,
+    quantity: u32,
+}
+
+impl Stock {
+    /// Checks if the stock holds parts of the same value and tolerance as `part`.
+    fn holds(&self, part: &Resistance) -> bool {
+        self.part.raw() == part.raw() && self.part.tolerance() == part.tolerance()
+    }
+}
+
+impl Display for Stock {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{:<12}{:<12}{}",
+            Value::new(self.part.raw()).to_string(),
+            format!("± {}%", self.part.tolerance()),
+            self.quantity
+        )
+    }
+}
+
+/// The collection of parts kept on hand, stored as a CSV file.
+#[derive(Debug, PartialEq, Default)]
+pub struct Inventory {
+    items: Vec<Stock>,
+}
+
+impl Inventory {
+    /// Determines the inventory file to use when one is not explicitly given.
+    pub fn default_path() -> Option<PathBuf> {
+        match std::env::var_os(INVENTORY_VAR) {
+            Some(p) => Some(PathBuf::from(p)),
+            None => {
+                let config = match std::env::var_os("XDG_CONFIG_HOME") {
+                    Some(p) => PathBuf::from(p),
+                    None => PathBuf::from(std::env::var_os("HOME")?).join(".config"),
+                };
+                Some(config.join("ohm").join("inventory.csv"))
+            }
+        }
+    }
+
+    /// Reads the inventory from `path`. A missing file is an empty inventory.
+    pub fn load(path: &Path) -> Result<Self, InventoryError> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let text = std::fs::read_to_string(path)?;
+        let mut items = Vec::new();
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || (i == 0 && line == HEADER) {
+                continue;
+            }
+            let malformed = || InventoryError::Malformed(path.to_path_buf(), i + 1);
+            let fields: Vec<&str> = line.split(',').map(|f| f.trim()).collect();
+            if fields.len() != 3 {
+                return Err(malformed());
+            }
+            let raw: Precision = fields[0].parse().map_err(|_| malformed())?;
+            let tol: Precision = fields[1].parse().map_err(|_| malformed())?;
+            // files written before values were exact have trailing zeros
+            let (raw, tol) = (raw.normalize(), tol.normalize());
+            let quantity: u32 = fields[2].parse().map_err(|_| malformed())?;
+            items.push(Stock {
+                part: Resistance::new(raw, tol, None),
+                quantity,
+            });
+        }
+        Ok(Self { items })
+    }
+
+    /// Writes the inventory to `path`, creating any missing directories.
+    pub fn save(&self, path: &Path) -> Result<(), InventoryError> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut text = String::from(HEADER);
+        text.push('\n');
+        for s in &self.items {
+            text.push_str(&format!(
+                "{},{},{}\n",
+                s.part.raw(),
+                s.part.tolerance(),
+                s.quantity
+            ));
+        }
+        Ok(std::fs::write(path, text)?)
+    }
+
+    /// Counts how many of `part` are on hand.
+    pub fn quantity(&self, part: &Resistance) -> u32 {
+        self.items
+            .iter()
+            .find(|s| s.holds(part))
+            .map_or(0, |s| s.quantity)
+    }
+
+    /// Adds `quantity` of `part` to the inventory.
+    pub fn add(&mut self, part: &Resistance, quantity: u32) {
+        match self.items.iter_mut().find(|s| s.holds(part)) {
+            Some(s) => s.quantity += quantity,
+            None => {
+                self.items.push(Stock {
+                    part: Resistance::new(part.raw(), part.tolerance(), None),
+                    quantity,
+                });
+                self.items.sort_by(|a, b| {
+                    a.part
+                        .raw()
+                        .cmp(&b.part.raw())
+                        .then(a.part.tolerance().cmp(&b.part.tolerance()))
+                });
+            }
+        }
+    }
+
+    /// Removes `quantity` of `part` from the inventory.
+    pub fn remove(&mut self, part: &Resistance, quantity: u32) -> Result<(), InventoryError> {
+        let on_hand = self.quantity(part);
+        if on_hand < quantity {
+            return Err(InventoryError::Insufficient(quantity, on_hand));
+        }
+        if let Some(s) = self.items.iter_mut().find(|s| s.holds(part)) {
+            s.quantity -= quantity;
+        }
+        // forget about parts that are used up
+        self.items.retain(|s| s.quantity > 0);
+        Ok(())
+    }
+
+    /// Lists every part that is in stock.
+    pub fn parts(&self) -> Vec<Resistance> {
+        self.items.iter().map(|s| s.part.clone()).collect()
+    }
+
+    /// Computes how many of the least plentiful part would be left after
+    /// taking every part in `used`. Returns `None` if there are not enough
+    /// parts on hand.
+    pub fn remaining(&self, used: &[Resistance]) -> Option<u32> {
+        used.iter()
+            .map(|part| {
+                let taken = used.iter().filter(|p| p == &part).count() as u32;
+                self.quantity(part).checked_sub(taken)
+            })
+            .min()
+            .flatten()
+    }
+
+    /// Lists the stock whose values fall within `range`.
+    pub fn within(&self, range: &ValueRange) -> Vec<&Stock> {
+        self.items
+            .iter()
+            .filter(|s| range.contains(s.part.raw()))
+            .collect()
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum InventoryError {
+    #[error("malformed inventory file {0:?} at line {1}")]
+    Malformed(PathBuf, usize),
+    #[error("cannot remove {0} parts when only {1} are in stock")]
+    Insufficient(u32, u32),
+    #[error("no inventory file is available (hint: set {INVENTORY_VAR} or use --inventory)")]
+    Unavailable,
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use std::str::FromStr;
+
+    #[test]
+    fn ut_add_and_remove() {
+        let mut inv = Inventory::default();
+        let part = Resistance::new(dec!(4700), dec!(5), None);
+        inv.add(&part, 10);
+        inv.add(&part, 2);
+        assert_eq!(inv.quantity(&part), 12);
+        // the same value at another tolerance is a different part
+        assert_eq!(inv.quantity(&Resistance::new(dec!(4700), dec!(1), None)), 0);
+        assert!(inv.remove(&part, 13).is_err());
+        inv.remove(&part, 12).unwrap();
+        assert_eq!(inv, Inventory::default());
+    }
+
+    #[test]
+    fn ut_remaining() {
+        let mut inv = Inventory::default();
+        let a = Resistance::new(dec!(4700), dec!(1), None);
+        let b = Resistance::new(dec!(2200), dec!(1), None);
+        inv.add(&a, 3);
+        inv.add(&b, 1);
+        assert_eq!(inv.remaining(std::slice::from_ref(&a)), Some(2));
+        assert_eq!(inv.remaining(&[a.clone(), a.clone()]), Some(1));
+        assert_eq!(inv.remaining(&[a.clone(), b.clone()]), Some(0));
+        assert_eq!(inv.remaining(&[b.clone(), b.clone()]), None);
+    }
+
+    #[test]
+    fn ut_within() {
+        let mut inv = Inventory::default();
+        inv.add(&Resistance::new(dec!(100), dec!(5), None), 1);
+        inv.add(&Resistance::new(dec!(4700), dec!(5), None), 1);
+        inv.add(&Resistance::new(dec!(1e6), dec!(5), None), 1);
+        let found = inv.within(&ValueRange::from_str("1k..10k").unwrap());
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].part.raw(), dec!(4700));
+    }
+
+    #[test]
+    fn ut_save_and_load() {
+        let mut inv = Inventory::default();
+        inv.add(&Resistance::new(dec!(4700), dec!(5), None), 10);
+        inv.add(&Resistance::new(dec!(0.22), dec!(0.1), None), 2);
+        let path = std::env::temp_dir()
+            .join(format!("ohm-{}", std::process::id()))
+            .join("inventory.csv");
+        inv.save(&path).unwrap();
+        let text = std::fs::read_to_string(&path).unwrap();
+        assert_eq!(text, "value,tolerance,quantity\n0.22,0.1,2\n4700,5,10\n");
+        assert_eq!(Inventory::load(&path).unwrap(), inv);
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+    }
+}
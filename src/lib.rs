@@ -1,5 +1,32 @@
+mod alias;
 mod band;
+mod bom;
+mod color;
+mod combine;
+mod config;
+mod eseries;
+#[cfg(feature = "ffi")]
+mod ffi;
+mod group;
+mod inventory;
+mod locale;
+mod measure;
 mod ohm;
+mod photo;
+#[cfg(feature = "python")]
+mod python;
+mod render;
 mod resistance;
+mod smd;
+mod term;
+mod value;
+#[cfg(feature = "wasm")]
+mod wasm;
 
-pub use ohm::Ohm;
+pub use alias::{Alias, AliasError};
+pub use band::{Band, BandError, Digit, Multiplier, Quantity, TempCoeff, Tolerance};
+pub use color::{nearest, rank, Body, ColorError, Lab, Rgb};
+pub use ohm::{Ohm, Precision};
+pub use ohm_core::{Milliohms, Ppm, Resistor, Standard, StandardError};
+pub use resistance::{Resistance, ResistanceError};
+pub use term::{ColorSupport, TermError, Terminal};
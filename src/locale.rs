@@ -0,0 +1,340 @@
+use crate::band::{self, Band, BandError, BAND_LIST};
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Environment variable to select the language of color names.
+pub const LANG_VAR: &str = "OHM_LANG";
+
+/// Bands in the order their names are listed for each language.
+const BANDS: [Band; 13] = [
+    Band::Black,
+    Band::Brown,
+    Band::Red,
+    Band::Orange,
+    Band::Yellow,
+    Band::Green,
+    Band::Blue,
+    Band::Violet,
+    Band::Grey,
+    Band::White,
+    Band::Gold,
+    Band::Silver,
+    Band::Pink,
+];
+
+/// Single-letter codes of the bands, which are the same in every language.
+const CODES: [char; 13] = [
+    'k', 'n', 'r', 'o', 'y', 'g', 'b', 'v', 'a', 'w', 'd', 's', 'p',
+];
+
+/// A language for reading and writing the names of colors.
+#[derive(Debug, PartialEq, Clone, Copy, Default)]
+pub enum Locale {
+    #[default]
+    En,
+    De,
+    Es,
+    Fr,
+    It,
+    Ja,
+    Nl,
+    Pt,
+}
+
+impl Locale {
+    pub const ALL: [Locale; 8] = [
+        Self::En,
+        Self::De,
+        Self::Es,
+        Self::Fr,
+        Self::It,
+        Self::Ja,
+        Self::Nl,
+        Self::Pt,
+    ];
+
+    /// Determines the language from the environment, preferring `OHM_LANG`
+    /// over the system's locale settings. Fails when `OHM_LANG` names an
+    /// unknown language.
+    pub fn from_env() -> Result<Option<Self>, LocaleError> {
+        Self::from_vars(|k| std::env::var(k).ok())
+    }
+
+    /// Determines the language from the environment variables read by `var`.
+    fn from_vars(var: impl Fn(&str) -> Option<String>) -> Result<Option<Self>, LocaleError> {
+        match var(LANG_VAR) {
+            Some(v) => Self::from_str(&v).map(Some),
+            // the system's locale is often set to a language without names
+            None => Ok(["LC_ALL", "LC_MESSAGES", "LANG"]
+                .iter()
+                .filter_map(|k| var(k))
+                .find(|v| !v.is_empty())
+                .and_then(|v| Self::from_str(&v).ok())),
+        }
+    }
+
+    /// Lists the names of the colors in the same order as [BANDS].
+    fn names(&self) -> [&'static str; 13] {
+        match self {
+            Self::En => [
+                "black", "brown", "red", "orange", "yellow", "green", "blue", "violet", "grey",
+                "white", "gold", "silver", "pink",
+            ],
+            Self::De => [
+                "schwarz", "braun", "rot", "orange", "gelb", "grün", "blau", "violett", "grau",
+                "weiß", "gold", "silber", "rosa",
+            ],
+            Self::Es => [
+                "negro", "marrón", "rojo", "naranja", "amarillo", "verde", "azul", "violeta",
+                "gris", "blanco", "dorado", "plateado", "rosa",
+            ],
+            Self::Fr => [
+                "noir", "marron", "rouge", "orange", "jaune", "vert", "bleu", "violet", "gris",
+                "blanc", "or", "argent", "rose",
+            ],
+            Self::It => [
+                "nero",
+                "marrone",
+                "rosso",
+                "arancione",
+                "giallo",
+                "verde",
+                "blu",
+                "viola",
+                "grigio",
+                "bianco",
+                "oro",
+                "argento",
+                "rosa",
+            ],
+            Self::Ja => [
+                "黒", "茶", "赤", "橙", "黄", "緑", "青", "紫", "灰", "白", "金", "銀", "桃",
+            ],
+            Self::Nl => [
+                "zwart", "bruin", "rood", "oranje", "geel", "groen", "blauw", "violet", "grijs",
+                "wit", "goud", "zilver", "roze",
+            ],
+            Self::Pt => [
+                "preto", "marrom", "vermelho", "laranja", "amarelo", "verde", "azul", "violeta",
+                "cinza", "branco", "dourado", "prateado", "rosa",
+            ],
+        }
+    }
+
+    /// Lists other common spellings of the colors that are also accepted as
+    /// input.
+    fn alternates(&self) -> &'static [(&'static str, Band)] {
+        match self {
+            Self::En => &[("gray", Band::Grey), ("purple", Band::Violet)],
+            Self::De => &[
+                ("gruen", Band::Green),
+                ("weiss", Band::White),
+                ("lila", Band::Violet),
+            ],
+            Self::Es => &[
+                ("marron", Band::Brown),
+                ("café", Band::Brown),
+                ("cafe", Band::Brown),
+            ],
+            Self::Fr => &[("doré", Band::Gold), ("argenté", Band::Silver)],
+            Self::It => &[],
+            Self::Ja => &[
+                ("黒色", Band::Black),
+                ("茶色", Band::Brown),
+                ("赤色", Band::Red),
+                ("橙色", Band::Orange),
+                ("オレンジ", Band::Orange),
+                ("黄色", Band::Yellow),
+                ("緑色", Band::Green),
+                ("青色", Band::Blue),
+                ("紫色", Band::Violet),
+                ("灰色", Band::Grey),
+                ("白色", Band::White),
+                ("金色", Band::Gold),
+                ("銀色", Band::Silver),
+                ("桃色", Band::Pink),
+                ("ピンク", Band::Pink),
+            ],
+            Self::Nl => &[("paars", Band::Violet)],
+            Self::Pt => &[("castanho", Band::Brown), ("roxo", Band::Violet)],
+        }
+    }
+
+    /// Returns the name of the `band`'s color in this language.
+    pub fn name(&self, band: &Band) -> &'static str {
+        let i = BANDS.iter().position(|b| b == band).unwrap();
+        self.names()[i]
+    }
+
+    /// Reads a band from its color name in this language, falling back to the
+    /// English names, single-letter codes, and hex colors.
+    pub fn parse(&self, s: &str) -> Result<Band, BandError> {
+        let text = s.trim().to_lowercase();
+        let localized = self
+            .names()
+            .iter()
+            .zip(BANDS.iter())
+            .chain(self.alternates().iter().map(|(n, b)| (n, b)))
+            .find(|(n, _)| **n == text)
+            .map(|(_, b)| b.clone());
+        match localized {
+            Some(b) => Ok(b),
+            None => band::parse(&text),
+        }
+    }
+
+    /// Writes the table of color codes with the color names in this language.
+    pub fn band_list(&self) -> String {
+        if self == &Self::En {
+            return BAND_LIST.to_string();
+        }
+        let mut list = format!("{}:", self.heading());
+        for (i, name) in self.names().iter().enumerate() {
+            // pad by display width since some scripts use wide characters
+            let width: usize = name
+                .chars()
+                .map(|c| if c > '\u{2E80}' { 2 } else { 1 })
+                .sum();
+            let digit = match i < 10 {
+                true => i.to_string(),
+                false => String::new(),
+            };
+            let line = format!(
+                "\n    {}   {}   {}{}{}",
+                CODES[i],
+                BANDS[i].iec(),
+                name,
+                " ".repeat(12_usize.saturating_sub(width)),
+                digit
+            );
+            list.push_str(line.trim_end());
+        }
+        list
+    }
+
+    fn heading(&self) -> &'static str {
+        match self {
+            Self::En => "Color Codes",
+            Self::De => "Farbcodes",
+            Self::Es => "Códigos de colores",
+            Self::Fr => "Codes de couleurs",
+            Self::It => "Codici dei colori",
+            Self::Ja => "カラーコード",
+            Self::Nl => "Kleurcodes",
+            Self::Pt => "Códigos de cores",
+        }
+    }
+
+    fn code(&self) -> &'static str {
+        match self {
+            Self::En => "en",
+            Self::De => "de",
+            Self::Es => "es",
+            Self::Fr => "fr",
+            Self::It => "it",
+            Self::Ja => "ja",
+            Self::Nl => "nl",
+            Self::Pt => "pt",
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = LocaleError;
+
+    /// Reads a language code, ignoring any region or encoding (ex: `de_DE.UTF-8`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let code = s
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+        match code.as_ref() {
+            // the "C" and "POSIX" system locales use English
+            "c" | "posix" => Ok(Self::En),
+            _ => Self::ALL
+                .into_iter()
+                .find(|l| l.code() == code)
+                .ok_or(LocaleError::Unknown(s.to_string())),
+        }
+    }
+}
+
+impl Display for Locale {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum LocaleError {
+    #[error("unknown language {0:?} (expects one of en, de, es, fr, it, ja, nl, pt)")]
+    Unknown(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_parse_locale() {
+        assert_eq!(Locale::from_str("de_DE.UTF-8").unwrap(), Locale::De);
+        assert_eq!(Locale::from_str("pt-BR").unwrap(), Locale::Pt);
+        assert_eq!(Locale::from_str("C").unwrap(), Locale::En);
+        assert!(Locale::from_str("xx").is_err());
+    }
+
+    #[test]
+    fn ut_locale_from_env() {
+        let env = |vars: &'static [(&str, &str)]| {
+            move |k: &str| {
+                vars.iter()
+                    .find(|(n, _)| *n == k)
+                    .map(|(_, v)| v.to_string())
+            }
+        };
+        assert_eq!(
+            Locale::from_vars(env(&[("OHM_LANG", "fr"), ("LANG", "de_DE.UTF-8")])).unwrap(),
+            Some(Locale::Fr)
+        );
+        assert_eq!(
+            Locale::from_vars(env(&[("LANG", "de_DE.UTF-8")])).unwrap(),
+            Some(Locale::De)
+        );
+        assert_eq!(
+            Locale::from_vars(env(&[("LANG", "sv_SE.UTF-8")])).unwrap(),
+            None
+        );
+        // a mistyped OHM_LANG is reported rather than passed over
+        assert!(matches!(
+            Locale::from_vars(env(&[("OHM_LANG", "jp"), ("LANG", "de_DE.UTF-8")])),
+            Err(LocaleError::Unknown(_))
+        ));
+    }
+
+    #[test]
+    fn ut_localized_bands() {
+        assert_eq!(Locale::De.parse("Grün").unwrap(), Band::Green);
+        assert_eq!(Locale::De.parse("weiss").unwrap(), Band::White);
+        assert_eq!(Locale::Ja.parse("茶色").unwrap(), Band::Brown);
+        assert_eq!(Locale::Fr.parse("or").unwrap(), Band::Gold);
+        // English names and codes are understood in every language
+        assert_eq!(Locale::Fr.parse("gold").unwrap(), Band::Gold);
+        assert_eq!(Locale::Nl.parse("k").unwrap(), Band::Black);
+        assert_eq!(Locale::Ja.parse("BN").unwrap(), Band::Brown);
+        assert!(Locale::Es.parse("schwarz").is_err());
+        assert_eq!(Locale::Es.name(&Band::Brown), "marrón");
+    }
+
+    #[test]
+    fn ut_band_list() {
+        assert_eq!(Locale::En.band_list(), BAND_LIST);
+        let list = Locale::De.band_list();
+        assert!(list.starts_with("Farbcodes:"));
+        assert!(list.contains("    n   BN   braun       1"));
+        assert!(list.contains("    d   GD   gold\n"));
+        assert!(list.contains("    s   SR   silber\n"));
+        assert!(list.ends_with("    p   PK   rosa"));
+    }
+}
@@ -0,0 +1,254 @@
+use crate::eseries::ESeries;
+use crate::ohm::Precision;
+use crate::resistance::Resistance;
+use crate::value;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal_macros::dec;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Temperature (in °C) at which a resistor's value and tolerance are
+/// specified.
+pub const REFERENCE_TEMP: Precision = dec!(25);
+
+/// A temperature in degrees Celsius, such as `40` or `40C`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Celsius(Precision);
+
+impl Celsius {
+    pub fn degrees(&self) -> Precision {
+        self.0
+    }
+}
+
+impl FromStr for Celsius {
+    type Err = MeasureError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        let text = text
+            .strip_suffix("°C")
+            .or_else(|| text.strip_suffix(['C', 'c']))
+            .unwrap_or(text);
+        match text.trim().parse::<Precision>() {
+            Ok(n) => Ok(Self(n.normalize())),
+            Err(_) => Err(MeasureError::InvalidTemp(s.to_string())),
+        }
+    }
+}
+
+impl Display for Celsius {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} °C", self.0)
+    }
+}
+
+/// A resistance measured on a part along with the range its marking allows.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Measurement {
+    measured: Precision,
+    nominal: Precision,
+    min: Precision,
+    max: Precision,
+}
+
+impl Measurement {
+    /// Compares the `measured` Ohms against the `part`.
+    ///
+    /// When measured at `temp`, the range is widened by the drift that the
+    /// part's temperature coefficient allows away from [REFERENCE_TEMP], since
+    /// the band only gives the size of the drift and not its direction.
+    pub fn new(part: &Resistance, measured: Precision, temp: Option<Celsius>) -> Self {
+        let drift = match (part.temp_coeff(), temp) {
+            (Some(ppm), Some(t)) => {
+                Precision::from(ppm) * (t.degrees() - REFERENCE_TEMP).abs() / dec!(1_000_000)
+            }
+            _ => Precision::ZERO,
+        };
+        Self {
+            measured,
+            nominal: part.raw(),
+            min: part.minimum() * (Precision::ONE - drift),
+            max: part.maximum() * (Precision::ONE + drift),
+        }
+    }
+
+    /// Lowest value in Ohms the part may measure.
+    pub fn minimum(&self) -> Precision {
+        self.min
+    }
+
+    /// Highest value in Ohms the part may measure.
+    pub fn maximum(&self) -> Precision {
+        self.max
+    }
+
+    /// Percent deviation of the measured value from the nominal value.
+    pub fn deviation(&self) -> Precision {
+        (self.measured - self.nominal)
+            .checked_div(self.nominal)
+            .unwrap_or_default()
+            * dec!(100)
+    }
+
+    /// Deviation of the measured value from the nominal value in parts per
+    /// million.
+    pub fn ppm(&self) -> Precision {
+        self.deviation() * dec!(10_000)
+    }
+
+    /// Checks if the measured value falls within the allowed range.
+    pub fn is_within(&self) -> bool {
+        self.measured >= self.min && self.measured <= self.max
+    }
+
+    /// Fails when the measured value falls outside of the allowed range.
+    pub fn verify(&self) -> Result<(), MeasureError> {
+        match self.is_within() {
+            true => Ok(()),
+            false => Err(MeasureError::OutOfTolerance(
+                self.measured,
+                self.min,
+                self.max,
+            )),
+        }
+    }
+}
+
+impl Display for Measurement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} Ω (deviation: {:+.3}%, {:+} ppm)",
+            value::plain(self.measured),
+            // round rather than cut off the digits, to agree with the ppm
+            self.deviation().round_dp(3),
+            self.ppm().round()
+        )
+    }
+}
+
+/// A standard part that could have produced a measured value.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Candidate {
+    series: ESeries,
+    part: Resistance,
+    likelihood: f64,
+}
+
+impl Candidate {
+    pub fn series(&self) -> ESeries {
+        self.series
+    }
+
+    pub fn part(&self) -> &Resistance {
+        &self.part
+    }
+
+    /// Chance (from 0 to 1) that the measured part is this part rather than
+    /// one of the other candidates.
+    pub fn likelihood(&self) -> f64 {
+        self.likelihood
+    }
+}
+
+/// Lists the standard parts, each made to the usual tolerance of its
+/// E-series, whose tolerance window contains the `measured` Ohms, from most to
+/// least likely.
+///
+/// The values of a part are assumed to be spread normally about its nominal
+/// value, reaching the edges of its tolerance window at three standard
+/// deviations.
+pub fn candidates(measured: Precision) -> Vec<Candidate> {
+    let mut found: Vec<Candidate> = Vec::new();
+    for series in ESeries::ALL {
+        let tol = series.tolerance();
+        let spread = tol / dec!(100);
+        // nominal values whose window reaches the measured value
+        let lo = measured / (Precision::ONE + spread);
+        let hi = measured / (Precision::ONE - spread);
+        for value in series.between(lo, hi) {
+            let part = Resistance::new(value, tol, None);
+            let sigma = (value * spread / dec!(3)).to_f64().unwrap_or(f64::NAN);
+            let z = (measured - value).to_f64().unwrap_or(f64::NAN) / sigma;
+            found.push(Candidate {
+                series,
+                part,
+                likelihood: (-z * z / 2.0).exp() / sigma,
+            });
+        }
+    }
+    let total: f64 = found.iter().map(|c| c.likelihood).sum();
+    found.iter_mut().for_each(|c| c.likelihood /= total);
+    found.sort_by(|x, y| y.likelihood.total_cmp(&x.likelihood));
+    found
+}
+
+#[derive(Error, Debug)]
+pub enum MeasureError {
+    #[error("invalid temperature {0:?}")]
+    InvalidTemp(String),
+    #[error("measured {} Ω is out of tolerance (min: {} Ω, max: {} Ω)", value::plain(*.0), value::plain(*.1), value::plain(*.2))]
+    OutOfTolerance(Precision, Precision, Precision),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_measured_deviation() {
+        let part = Resistance::new(dec!(1000), dec!(5), None);
+        let m = Measurement::new(&part, dec!(1013), None);
+        assert_eq!(m.deviation(), dec!(1.3));
+        assert_eq!(m.ppm(), dec!(13_000));
+        assert!(m.is_within());
+        assert_eq!(m.to_string(), "1013.0 Ω (deviation: +1.300%, +13000 ppm)");
+        let m = Measurement::new(
+            &Resistance::new(dec!(4700), dec!(1), None),
+            dec!(4710),
+            None,
+        );
+        assert_eq!(m.to_string(), "4710.0 Ω (deviation: +0.213%, +2128 ppm)");
+        let m = Measurement::new(&part, dec!(1051), None);
+        assert!(matches!(m.verify(), Err(MeasureError::OutOfTolerance(..))));
+        // a temperature is ignored without a temperature coefficient
+        let m = Measurement::new(&part, dec!(1051), Some(Celsius(dec!(75))));
+        assert!(!m.is_within());
+    }
+
+    #[test]
+    fn ut_candidates() {
+        let found = candidates(dec!(4680));
+        assert!(found
+            .iter()
+            .all(|c| c.part().minimum() <= dec!(4680) && c.part().maximum() >= dec!(4680)));
+        assert!(found
+            .iter()
+            .any(|c| c.series() == ESeries::E24 && c.part().raw() == dec!(4700)));
+        // a close 2% part is likelier than a 5% part
+        assert_eq!(found[0].series(), ESeries::E48);
+        assert_eq!(found[0].part().raw(), dec!(4640));
+        assert!(found
+            .windows(2)
+            .all(|w| w[0].likelihood() >= w[1].likelihood()));
+        let total: f64 = found.iter().map(|c| c.likelihood()).sum();
+        assert!((total - 1.0).abs() < 1e-9);
+        assert!(candidates(dec!(0)).is_empty());
+    }
+
+    #[test]
+    fn ut_temperature_compensation() {
+        // 100 ppm/K over 50 K allows another 0.5% of drift
+        let part = Resistance::new(dec!(1000), dec!(1), Some(100));
+        let m = Measurement::new(&part, dec!(1013), Some(Celsius(dec!(75))));
+        assert_eq!(m.minimum(), dec!(985.05));
+        assert_eq!(m.maximum(), dec!(1015.05));
+        assert!(m.is_within());
+        assert!(!Measurement::new(&part, dec!(1013), None).is_within());
+        assert_eq!(Celsius::from_str("-5 °C").unwrap(), Celsius(dec!(-5)));
+        assert_eq!(Celsius::from_str("40C").unwrap(), Celsius(dec!(40)));
+        assert!(Celsius::from_str("warm").is_err());
+    }
+}
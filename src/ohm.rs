@@ -1,104 +1,708 @@
-use crate::band::*;
+use crate::alias::Alias;
+use crate::band::{self, *};
+use crate::bom::Bom;
+use crate::color::Body;
+use crate::combine::{self, Combination};
+use crate::config::{Config, ConfigError, Format};
+use crate::eseries::ESeries;
+use crate::group::BandGroup;
+use crate::inventory::{Inventory, InventoryError};
+use crate::locale::{Locale, LANG_VAR};
+use crate::measure::{self, Celsius, Measurement};
+use crate::photo::{self, Photo, PhotoError};
+use crate::render;
 use crate::resistance::Resistance;
+use crate::term::{ColorSupport, Terminal};
+use crate::value::{self, Percent, Value, ValueRange};
+use ohm_core::Standard;
+use rust_decimal_macros::dec;
 use std::fmt::Display;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
 
 use cliproc::{cli, proc, stage::*};
 use cliproc::{Arg, Cli, Command, Help};
 
-pub type Precision = f64;
+/// Exact decimal number used for resistances and percentages, so values such
+/// as 0.22 Ω are never disturbed by binary rounding.
+pub type Precision = rust_decimal::Decimal;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug)]
 pub struct Ohm {
     no_color: bool,
-    bands: Option<Vec<Band>>,
+    color: Option<ColorSupport>,
+    body: Option<Body>,
+    list: bool,
+    lang: Option<Locale>,
+    iec: bool,
+    accessible: bool,
+    args: Option<Vec<String>>,
+    find: Option<Value>,
+    series: Option<ESeries>,
+    standard: Option<Standard>,
+    error: Option<Percent>,
+    value: Option<Value>,
+    tol: Option<Percent>,
+    inventory: Option<PathBuf>,
+    add: Option<u32>,
+    remove: Option<u32>,
+    stock: Option<ValueRange>,
+    bom: Option<PathBuf>,
+    output: Option<PathBuf>,
+    width: Option<u32>,
+    caption: bool,
+    photo: Option<PathBuf>,
+    measured: Option<Value>,
+    temp: Option<Celsius>,
+    three_band_tol: Option<Percent>,
+    smd_tol: Option<Percent>,
+    aliases: Vec<Alias>,
+    config_error: Option<ConfigError>,
 }
 
+/// A task named by the first argument, which reads the arguments after it.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum Task {
+    /// Search the inventory for parts that can replace a value.
+    Substitute,
+    /// Draw the resistor to an image file.
+    Render,
+    /// Compare a measured value against the part's tolerance.
+    Check,
+}
+
+impl Task {
+    /// Finds the task called `name`.
+    fn named(name: &str) -> Option<Self> {
+        match name {
+            "substitute" => Some(Self::Substitute),
+            "render" => Some(Self::Render),
+            "check" => Some(Self::Check),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Task {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Substitute => write!(f, "substitute"),
+            Self::Render => write!(f, "render"),
+            Self::Check => write!(f, "check"),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+enum UsageError {
+    #[error("\"ohm {0}\" expects {1}")]
+    Arguments(Task, &'static str),
+    #[error("\"ohm {0}\" requires option {1}")]
+    Missing(Task, &'static str),
+    #[error("option {0} is only used by \"ohm {1}\"")]
+    Misplaced(&'static str, Task),
+}
+
+/// Default E-series to draw standard parts from.
+const DEFAULT_SERIES: ESeries = ESeries::E24;
+
+/// Default maximum error (in percent) allowed when searching for combinations.
+const DEFAULT_ERROR: Precision = dec!(1);
+
+/// Maximum number of combinations to display.
+const MAX_RESULTS: usize = 10;
+
+/// Default tolerance (in percent) of a part given by its value.
+const DEFAULT_TOLERANCE: Precision = dec!(5);
+
 impl Ohm {
-    fn calculate_raw(res: &Resistor) -> Precision {
-        let mut result: usize = 0;
-        // add the first digit
-        result += <Digit as Into<u8>>::into(res.first) as usize;
+    fn compute(&self, resistor: &Resistor) -> Result<Resistance, BandError> {
+        let part = resistor.resistance_with(&self.standard())?;
+        Ok(Resistance::new(
+            value::ohms(part.raw()),
+            self.tolerance(resistor)?,
+            part.temp_coeff(),
+        ))
+    }
+
+    /// Determines the tolerance of `resistor`, using the configured tolerance
+    /// when it has no tolerance band.
+    fn tolerance(&self, resistor: &Resistor) -> Result<Precision, BandError> {
+        match (resistor.tolerance(), self.three_band_tol) {
+            (Tolerance::Default, Some(t)) => Ok(t.value()),
+            (t, _) => self.standard().tolerance(t).map(value::percent),
+        }
+    }
+
+    /// Determines the tables used to read the tolerance and temperature
+    /// coefficient bands.
+    fn standard(&self) -> Standard {
+        self.standard.unwrap_or_default()
+    }
+
+    /// Splits the arguments into the task they name, if any, and the
+    /// arguments for it.
+    fn task(&self) -> (Option<Task>, &[String]) {
+        let args = self.args.as_deref().unwrap_or_default();
+        match args.split_first() {
+            Some((name, rest)) => match Task::named(name) {
+                Some(task) => (Some(task), rest),
+                None => (None, args),
+            },
+            None => (None, args),
+        }
+    }
+
+    /// Reads a band from its name, checking the aliases first.
+    fn parse_band(&self, s: &str) -> Result<Band, BandError> {
+        match Alias::lookup(&self.aliases, s) {
+            Some(b) => Ok(b.clone()),
+            None => self.locale().parse(s),
+        }
+    }
 
-        // shift digits to the left by 1 position
-        result = (result * 10) + <Digit as Into<u8>>::into(res.second) as usize;
+    /// Determines the language for the names of colors.
+    fn locale(&self) -> Locale {
+        self.lang.unwrap_or_default()
+    }
 
-        if let Some(third) = res.third {
-            // shift digits to the left by 1 position
-            result = (result * 10) + <Digit as Into<u8>>::into(third) as usize;
+    /// Names a band's color in the requested language or abbreviation.
+    fn band_name(&self, band: &Band) -> &'static str {
+        match self.iec {
+            true => band.iec(),
+            false => self.locale().name(band),
         }
+    }
 
-        let mult: i8 = res.multiplier.into();
-        match mult >= 0 {
-            true => result as Precision * 10_usize.pow(mult.abs() as u32) as Precision,
-            false => result as Precision / 10_usize.pow(mult.abs() as u32) as Precision,
+    /// Determines what the terminal can display, letting `--color` and
+    /// `--no-color` override what is detected.
+    fn terminal(&self) -> Terminal {
+        let mut term = *Terminal::current();
+        if let Some(colors) = self.color {
+            term.colors = colors;
         }
+        if self.no_color {
+            term.colors = ColorSupport::None;
+        }
+        term
+    }
+
+    /// Marks a band with its symbol and code, which can be told apart without
+    /// seeing colors.
+    fn mark(&self, band: &Band) -> String {
+        let mark = format!("{}{}", band.symbol(), band.code());
+        self.terminal().paint(&mark, band.rgb())
     }
 
-    fn compute(resistor: Resistor) -> Resistance {
-        Resistance::new(
-            Self::calculate_raw(&resistor),
-            resistor.tolerance.into(),
-            resistor.temp_coeff,
-        )
+    /// Writes a table that spells out the role of each band of `resistor`,
+    /// which reads as `part`. The color comes last because its name may be
+    /// wider on screen than its number of characters.
+    fn role_table(&self, bands: &[Band], resistor: &Resistor, part: &Resistance) -> String {
+        let mut table = format!(
+            "{:<6}{:<6}{:<14}{:<12}{}",
+            "Band", "Mark", "Role", "Value", "Color"
+        );
+        for (i, (band, (role, value))) in bands.iter().zip(roles(resistor, part)).enumerate() {
+            table.push_str(&format!(
+                "\n{:<6}{}    {:<14}{:<12}{}",
+                i + 1,
+                self.mark(band),
+                role,
+                value,
+                self.band_name(band)
+            ));
+        }
+        // a missing tolerance band still sets the tolerance
+        if bands.len() == 3 {
+            table.push_str(&format!(
+                "\n{:<6}{:<6}{:<14}{:<12}{}",
+                "-",
+                "",
+                "tolerance",
+                format!("±{}%", part.tolerance()),
+                "(none)"
+            ));
+        }
+        table
+    }
+
+    /// Formats the sequence of bands for display.
+    fn identify(&self, bands: Vec<Band>) -> String {
+        let group = BandGroup::from(bands);
+        if self.accessible {
+            return group.labeled(|b| self.mark(b));
+        }
+        let term = self.terminal();
+        match (self.iec, term.colors) {
+            (true, _) => group.labeled(|b| b.iec().to_string()),
+            // name the colors when they cannot be shown
+            (false, ColorSupport::None) => group.localized(self.locale()),
+            // draw the bands as blocks on the body so dark and light bands
+            // stand out from the terminal's own background
+            (false, _) => {
+                let body = self.body.unwrap_or_default();
+                group.on_body(|b| term.fill(" ", b.rgb()), |t| term.fill(t, body.rgb()))
+            }
+        }
+    }
+
+    /// Formats the bands of a `value` from the `series`, or `-` when the bands
+    /// cannot mark it.
+    fn identify_part(&self, value: Precision, series: ESeries) -> String {
+        band::tolerance(series.tolerance(), &self.standard())
+            .and_then(|t| band::encode(value, series.digits(), t))
+            .map_or(String::from("-"), |bands| self.identify(bands))
+    }
+
+    /// Searches for combinations of standard parts that produce the `target`
+    /// resistance.
+    fn find_combinations(&self, target: Value) -> proc::Result {
+        let series = self.series.unwrap_or(DEFAULT_SERIES);
+        let max_error = match self.error {
+            Some(p) => p.value(),
+            None => DEFAULT_ERROR,
+        };
+        let parts: Vec<Resistance> = series
+            .between(target.ohms() / dec!(100), target.ohms() * dec!(100))
+            .into_iter()
+            .map(|v| Resistance::new(v, series.tolerance(), None))
+            .collect();
+        let found: Vec<Combination> =
+            combine::search(target.ohms(), &parts, max_error, combine::MAX_PARTS);
+        if found.is_empty() {
+            println!(
+                "No combinations of {} parts are within {}% of {}",
+                series, max_error, target
+            );
+            return Ok(());
+        }
+        for (i, combo) in found.iter().take(MAX_RESULTS).enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!(
+                "{} = {} (error: {:+.3}%)",
+                combo.network(),
+                Value::new(combo.network().value()),
+                combo.error().round_dp(3)
+            );
+            for part in combo.network().parts() {
+                println!(
+                    "    {:<10}{}",
+                    Value::new(part.raw()).to_string(),
+                    self.identify_part(part.raw(), series)
+                );
+            }
+        }
+        println!("\nStandard: {}", self.standard());
+        Ok(())
+    }
+
+    /// Determines which inventory file to read and write.
+    fn inventory_path(&self) -> Option<PathBuf> {
+        self.inventory.clone().or_else(Inventory::default_path)
+    }
+
+    /// Lists the parts in stock whose values fall within the `range`.
+    fn list_stock(&self, range: ValueRange) -> proc::Result {
+        let path = self.inventory_path().ok_or(InventoryError::Unavailable)?;
+        let inventory = Inventory::load(&path)?;
+        let found = inventory.within(&range);
+        if found.is_empty() {
+            println!("No parts are in stock between {}", range);
+            return Ok(());
+        }
+        println!("Value       Tolerance   Quantity");
+        for s in found {
+            println!("{}", s);
+        }
+        Ok(())
+    }
+
+    /// Searches the inventory for single parts or pairs of parts whose
+    /// worst-case resistance stays within the tolerance of the `target`.
+    fn find_substitutes(&self, target: Value) -> proc::Result {
+        let tol = self.tol.map_or(DEFAULT_TOLERANCE, |t| t.value());
+        let required = Resistance::new(target.ohms(), tol, None);
+        let path = self.inventory_path().ok_or(InventoryError::Unavailable)?;
+        let inventory = Inventory::load(&path)?;
+
+        let mut found: Vec<(Combination, u32)> =
+            combine::search(target.ohms(), &inventory.parts(), tol, 2)
+                .into_iter()
+                .filter(|c| {
+                    c.network().minimum() >= required.minimum()
+                        && c.network().maximum() <= required.maximum()
+                })
+                .filter_map(|c| {
+                    let left = inventory.remaining(&c.network().parts())?;
+                    Some((c, left))
+                })
+                .collect();
+        // rank by closeness and then by the amount of stock left over
+        found.sort_by(|(x, x_left), (y, y_left)| {
+            x.error()
+                .abs()
+                .cmp(&y.error().abs())
+                .then(y_left.cmp(x_left))
+        });
+        if found.is_empty() {
+            println!("No parts in stock can substitute for {} ± {}%", target, tol);
+            return Ok(());
+        }
+        for (i, (combo, left)) in found.iter().take(MAX_RESULTS).enumerate() {
+            if i > 0 {
+                println!();
+            }
+            println!(
+                "{} = {} (error: {:+.3}%)",
+                combo.network(),
+                Value::new(combo.network().value()),
+                combo.error().round_dp(3)
+            );
+            println!(
+                "    worst case: {} to {}",
+                Value::new(combo.network().minimum()),
+                Value::new(combo.network().maximum())
+            );
+            println!("    stock left: {}", left);
+        }
+        Ok(())
+    }
+
+    /// Reads the bands from a photo of a resistor, reporting how well each
+    /// stripe matches its band and the other bands it could be.
+    fn read_photo(&self, path: &Path) -> Result<Vec<Band>, Box<dyn std::error::Error>> {
+        let stripes = Photo::load(path)?.stripes()?;
+        if !(3..=6).contains(&stripes.len()) {
+            return Err(PhotoError::StripeCount(stripes.len()).into());
+        }
+        for (i, s) in stripes.iter().enumerate() {
+            print!(
+                "Stripe {}: {:<8}({:.0}%)",
+                i + 1,
+                self.band_name(s.band()),
+                s.confidence() * 100.0
+            );
+            if s.is_ambiguous() {
+                let others: Vec<String> = s
+                    .candidates()
+                    .iter()
+                    .map(|(b, p)| format!("{} ({:.0}%)", self.band_name(b), p * 100.0))
+                    .collect();
+                print!("  candidates: {}", others.join(", "));
+            }
+            println!();
+        }
+        // take the most likely reading that forms a valid color code
+        let readings = photo::readings(&stripes);
+        match readings
+            .iter()
+            .find(|bands| Resistor::decode(bands).is_ok())
+        {
+            Some(bands) => Ok(bands.clone()),
+            None => Err(Resistor::decode(&readings[0]).unwrap_err().into()),
+        }
+    }
+
+    /// Lists the standard parts that could have produced the `measured` value,
+    /// from most to least likely.
+    fn identify_measured(&self, measured: Value) -> proc::Result {
+        let found = measure::candidates(measured.ohms());
+        if found.is_empty() {
+            println!("No standard parts can measure {}", measured);
+            return Ok(());
+        }
+        println!("Value       Tolerance   Series  Likelihood  Bands");
+        for c in found.iter().take(MAX_RESULTS) {
+            let part = c.part();
+            println!(
+                "{:<12}{:<12}{:<8}{:<12}{}",
+                Value::new(part.raw()).to_string(),
+                format!("±{}%", part.tolerance()),
+                c.series().to_string(),
+                format!("{:.1}%", c.likelihood() * 100.0),
+                self.identify_part(part.raw(), c.series())
+            );
+        }
+        println!("\nStandard: {}", self.standard());
+        Ok(())
+    }
+
+    /// Compares the measured value against the range allowed by `part`,
+    /// failing when it is out of tolerance.
+    fn check_measured(&self, part: &Resistance) -> proc::Result {
+        let measured = match self.measured {
+            Some(m) => m,
+            None => return Ok(()),
+        };
+        let m = Measurement::new(part, measured.ohms(), self.temp);
+        if let (Some(t), Some(_)) = (self.temp, part.temp_coeff()) {
+            println!(
+                "Range at {}: {} Ω to {} Ω",
+                t,
+                value::plain(m.minimum()),
+                value::plain(m.maximum())
+            );
+        }
+        println!("Measured: {}", m);
+        m.verify()?;
+        println!("Within tolerance");
+        Ok(())
+    }
+
+    /// Applies any requested changes to the stock of `part` and reports how
+    /// many are on hand.
+    fn update_stock(&self, part: &Resistance) -> proc::Result {
+        let path = match self.inventory_path() {
+            Some(p) => p,
+            None => match self.add.is_some() || self.remove.is_some() {
+                true => return Err(InventoryError::Unavailable.into()),
+                false => return Ok(()),
+            },
+        };
+        if self.add.is_none() && self.remove.is_none() {
+            // only report on the stock when an inventory is being kept
+            if path.exists() {
+                match Inventory::load(&path)?.quantity(part) {
+                    0 => println!("Stock: none (not stocked)"),
+                    n => println!("Stock: {}", n),
+                }
+            }
+            return Ok(());
+        }
+        let mut inventory = Inventory::load(&path)?;
+        if let Some(n) = self.add {
+            inventory.add(part, n);
+        }
+        if let Some(n) = self.remove {
+            inventory.remove(part, n)?;
+        }
+        inventory.save(&path)?;
+        println!("Stock: {}", inventory.quantity(part));
+        Ok(())
     }
 }
 
-impl Command for Ohm {
-    fn interpret(cli: &mut Cli<Memory>) -> cli::Result<Self> {
+impl Ohm {
+    /// Reads the command-line data, with `config` filling in for anything
+    /// omitted and `config_error` held back until the command runs.
+    // the error is cliproc's own, as returned from `Command::interpret`
+    #[allow(clippy::result_large_err)]
+    fn interpret_with(
+        cli: &mut Cli<Memory>,
+        config: Config,
+        config_error: Option<ConfigError>,
+    ) -> cli::Result<Self> {
         // check for 1st overall help flag
         cli.help(Help::with(QUICK_HELP))?;
         cli.raise_help()?;
         cli.lower_help();
-        // check for 2nd quick color code list flag
-        cli.help(Help::with(BAND_LIST).flag("list").switch('l'))?;
-        cli.raise_help()?;
-        cli.lower_help();
         // return to overall help flag
         cli.help(Help::with(QUICK_HELP))?;
+        // cliproc reads every flag before any option
+        let no_color = cli.check(Arg::flag("no-color"))?;
+        let iec = cli.check(Arg::flag("iec"))?;
+        let accessible = cli.check(Arg::flag("accessible"))?;
+        let list = cli.check(Arg::flag("list").switch('l'))?;
+        let caption = cli.check(Arg::flag("caption"))?;
+        let color = cli.get(Arg::option("color").value("mode"))?;
+        // any flag that picks a style replaces the configured format
+        let format = config
+            .format
+            .filter(|_| !(no_color || color.is_some() || iec || accessible));
         // interpret the command-line data into the [Ohm] struct
         Ok(Self {
-            no_color: cli.check(Arg::flag("no-color"))?,
-            bands: cli.get_between(Arg::positional("band"), 3..=6)?,
+            no_color: no_color || format == Some(Format::Names),
+            color: color.or(config.color),
+            body: cli.get(Arg::option("body").value("color"))?,
+            // the color code list is printed in the chosen language
+            list,
+            // the configured language replaces the system's locale but not $OHM_LANG
+            lang: match cli.get(Arg::option("lang").value("code"))? {
+                Some(l) => Some(l),
+                None => match std::env::var_os(LANG_VAR) {
+                    Some(_) => None,
+                    None => config.lang,
+                },
+            },
+            iec: iec || format == Some(Format::Iec),
+            accessible: accessible || format == Some(Format::Accessible),
+            find: cli.get(Arg::option("find").value("value"))?,
+            series: cli.get(Arg::option("series").value("E"))?.or(config.series),
+            standard: cli
+                .get(Arg::option("standard").value("name"))?
+                .or(config.standard),
+            error: cli.get(Arg::option("error").value("percent"))?,
+            value: cli.get(Arg::option("value").value("value"))?,
+            tol: cli.get(Arg::option("tol").value("percent"))?,
+            inventory: cli.get(Arg::option("inventory").value("file"))?,
+            add: cli.get(Arg::option("add").value("count"))?,
+            remove: cli.get(Arg::option("remove").value("count"))?,
+            stock: cli.get(Arg::option("stock").value("range"))?,
+            bom: cli.get(Arg::option("bom").value("file"))?,
+            output: cli.get(Arg::option("output").switch('o').value("file"))?,
+            width: cli.get(Arg::option("width").value("pixels"))?,
+            caption,
+            photo: cli.get(Arg::option("photo").value("file"))?,
+            measured: cli.get(Arg::option("measured").value("value"))?,
+            temp: cli.get(Arg::option("temp").value("celsius"))?,
+            // aliases from the command-line replace configured ones of the same name
+            aliases: {
+                let mut aliases: Vec<Alias> = cli
+                    .get_all(Arg::option("alias").value("name=color"))?
+                    .unwrap_or_default();
+                for alias in config.aliases {
+                    if Alias::lookup(&aliases, alias.name()).is_none() {
+                        aliases.push(alias);
+                    }
+                }
+                aliases
+            },
+            // a task may be named before its arguments, which are otherwise
+            // the bands
+            args: cli.get_all(Arg::positional("band"))?,
+            three_band_tol: config.three_band_tol,
+            smd_tol: config.smd_tol,
+            config_error,
         })
     }
+}
 
-    fn execute(self) -> proc::Result {
-        let bands = match self.bands {
-            Some(b) => b,
-            None => {
+impl Command for Ohm {
+    fn interpret(cli: &mut Cli<Memory>) -> cli::Result<Self> {
+        // settings from the configuration file fill in for omitted flags, and
+        // a broken file is reported once the command runs
+        match Config::load() {
+            Ok(config) => Self::interpret_with(cli, config, None),
+            Err(e) => Self::interpret_with(cli, Config::default(), Some(e)),
+        }
+    }
+
+    fn execute(mut self) -> proc::Result {
+        if let Some(e) = self.config_error.take() {
+            return Err(e.into());
+        }
+        if self.lang.is_none() {
+            self.lang = Locale::from_env()?;
+        }
+        if self.list {
+            println!("{}", self.locale().band_list());
+            return Ok(());
+        }
+        if let Some(target) = self.find {
+            return self.find_combinations(target);
+        }
+        if let Some(range) = self.stock {
+            return self.list_stock(range);
+        }
+        if let (Some(Task::Substitute), args) = self.task() {
+            let target = match args {
+                [value] => value.parse()?,
+                _ => return Err(UsageError::Arguments(Task::Substitute, "a single <value>").into()),
+            };
+            return self.find_substitutes(target);
+        }
+        if let Some(path) = &self.bom {
+            let bom = Bom::parse(&std::fs::read_to_string(path)?)?;
+            print!(
+                "{}",
+                bom.annotate(
+                    self.tol.map_or(DEFAULT_TOLERANCE, |t| t.value()),
+                    &self.standard()
+                )
+            );
+            // keep the annotated CSV on stdout free of anything else
+            eprintln!("Standard: {}", self.standard());
+            return Ok(());
+        }
+        let (task, args) = self.task();
+        match (task, self.measured) {
+            (Some(Task::Check), None) => {
+                return Err(UsageError::Missing(Task::Check, "--measured <value>").into())
+            }
+            (Some(Task::Check), Some(_)) => (),
+            // a measured value alone asks which part it could be
+            (_, Some(_))
+                if task.is_some()
+                    || !args.is_empty()
+                    || self.value.is_some()
+                    || self.photo.is_some() =>
+            {
+                return Err(UsageError::Misplaced("--measured", Task::Check).into())
+            }
+            _ => (),
+        }
+        if let Some(value) = self.value {
+            // the configured tolerance is only for parts given by their value
+            let tol = self
+                .tol
+                .or(self.smd_tol)
+                .map_or(DEFAULT_TOLERANCE, |t| t.value());
+            let resistance = Resistance::new(value.ohms(), tol, None);
+            println!("Resistance: {}", resistance);
+            self.check_measured(&resistance)?;
+            return self.update_stock(&resistance);
+        }
+        match (task, &self.output) {
+            (Some(Task::Render), None) => {
+                return Err(UsageError::Missing(Task::Render, "--output <file>").into())
+            }
+            (None, Some(_)) => return Err(UsageError::Misplaced("--output", Task::Render).into()),
+            _ => (),
+        }
+        let bands = match (args, &self.photo) {
+            ([], Some(path)) => self.read_photo(path)?,
+            ([], None) => {
+                match task {
+                    Some(Task::Check) => {
+                        return Err(UsageError::Arguments(
+                            Task::Check,
+                            "<band>..., --photo <file>, or --value <value>",
+                        )
+                        .into())
+                    }
+                    Some(task) => {
+                        return Err(
+                            UsageError::Arguments(task, "<band>... or --photo <file>").into()
+                        )
+                    }
+                    None => (),
+                }
+                if let Some(measured) = self.measured {
+                    return self.identify_measured(measured);
+                }
                 println!("{}", QUICK_HELP);
                 return Ok(());
             }
+            (b, _) => b
+                .iter()
+                .map(|s| self.parse_band(s))
+                .collect::<Result<Vec<Band>, BandError>>()?,
         };
-        let resistor = match Resistor::decode(bands.clone()) {
-            Ok(r) => Ok(r),
-            Err(e) => {
-                // try to see if the bands were entered in reverse
-                let mut rev_bands = bands.clone();
-                rev_bands.reverse();
-                let rev_result = Resistor::decode(rev_bands);
-                match rev_result {
-                    // the bands were entered in reverse order
-                    Ok(_) => Err(BandError::ReversedBandOrder(e.to_string())),
-                    // the bands are just flat-out wrong
-                    Err(_) => Err(e),
-                }
-            }
-        }?;
+        let resistor = band::read(&bands)?;
+        let resistance = self.compute(&resistor)?;
 
         // resistor: Resistor,
-        let group = BandGroup::from(bands.clone());
-        println!(
-            "Identification: {}",
-            match self.no_color {
-                true => group.ascii(),
-                false => group.to_string(),
-            }
-        );
+        println!("Identification: {}", self.identify(bands.clone()));
+        if self.accessible {
+            println!("{}", self.role_table(&bands, &resistor, &resistance));
+        }
 
-        let resistance = Self::compute(resistor);
         println!("Resistance: {}", resistance);
-        Ok(())
+        println!("Standard: {}", self.standard());
+        if let (Some(Task::Render), Some(path)) = (task, &self.output) {
+            let caption = resistance.to_string();
+            render::save(
+                &BandGroup::from(bands),
+                self.body.unwrap_or_default(),
+                self.caption.then_some(caption.as_str()),
+                self.width,
+                path,
+            )?;
+        }
+        self.check_measured(&resistance)?;
+        self.update_stock(&resistance)
     }
 }
 
@@ -107,267 +711,263 @@ A resistor color code calculator.
 
 Usage:
     ohm [options] <band>...
+    ohm [options] --measured <value>
+    ohm [options] --find <value>
+    ohm [options] --value <value>
+    ohm [options] --stock <range>
+    ohm substitute <value> [options]
+    ohm [options] --bom <file>
+    ohm [options] --photo <file>
+    ohm render <band>... -o <file> [options]
+    ohm check <band>... --measured <value> [options]
+    ohm check --value <value> --measured <value> [options]
+
+Tasks:
+    substitute <value>  search the inventory for parts that can replace a value
+    render <band>...    draw the resistor to an image file given by --output
+    check <band>...     check the value given by --measured against the part's
+                        tolerance
 
 Arguments:
-    <band>...       colors from left to right (between 3 and 6)  
+    <band>...           colors from left to right (between 3 and 6)  
 
 Options:
-    --help, -h      print this help information and exit
-    --list, -l      print the set of color codes and exit
-    --no-color      disable color formatting
+    --help, -h          print this help information and exit
+    --list, -l          print the set of color codes and exit
+    --no-color          disable color formatting
+    --color <mode>      colors to use: never, 16, 256, or truecolor (default:
+                        detected from the terminal)
+    --body <color>      color of the resistor's body: carbon, metal, or a hex
+                        color (default: carbon)
+    --lang <code>       language of color names (default: $OHM_LANG)
+    --iec               identify bands by their IEC 60757 codes (ex: BN)
+    --accessible        identify bands by symbols and codes instead of colors
+                        and spell out the role of each band
+    --alias <name=color>
+                        read <name> as another name for a color (ex: au=gold)
+    --find <value>      search for combinations of parts that produce a value
+    --series <E>        E-series to draw parts from (default: E24)
+    --error <percent>   maximum error of a combination (default: 1%)
+    --standard <name>   color code tables for the tolerance and temperature
+                        coefficient bands: classic or iec60062-2016 (default:
+                        classic)
+    --value <value>     specify a part by its value instead of its bands
+    --tol <percent>     tolerance of the part given by --value or to substitute
+                        (default: 5%)
+    --inventory <file>  inventory file to use (default: $OHM_INVENTORY)
+    --add <count>       add the part to the inventory
+    --remove <count>    remove the part from the inventory
+    --stock <range>     list the parts in stock between <low>..<high>
+    --bom <file>        annotate a BOM (CSV) with color codes and SMD codes
+    --output, -o <file> image file to draw to (.svg or .png)
+    --width <pixels>    width of a .png image (default: 400)
+    --caption           write the resistance below the drawn resistor
+    --photo <file>      read the bands from a photo of a resistor (.png or .jpg)
+    --measured <value>  measured value to check, or alone to list the standard
+                        parts it could be
+    --temp <celsius>    temperature of the measurement, allowing for the drift
+                        of the temperature coefficient from 25 °C
 ";
 
-enum BandGroup {
-    // 3-band: -[|||    ]-
-    R3(Band, Band, Band),
-    // 4-band: -[|||  | ]-
-    R4(Band, Band, Band, Band),
-    // 5-band: -[|||| | ]-
-    R5(Band, Band, Band, Band, Band),
-    // 6-band: -[|||| ||]-
-    R6(Band, Band, Band, Band, Band, Band),
-}
-
-impl From<Vec<Band>> for BandGroup {
-    fn from(vec: Vec<Band>) -> Self {
-        let mut vec = vec;
-        vec.reverse();
-        match vec.len() {
-            3 => Self::R3(vec.pop().unwrap(), vec.pop().unwrap(), vec.pop().unwrap()),
-            4 => Self::R4(
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-            ),
-            5 => Self::R5(
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-            ),
-            6 => Self::R6(
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-                vec.pop().unwrap(),
-            ),
-            _ => panic!("unsupported band length {}", vec.len()),
-        }
+/// Describes the role and value of each band of `resistor`, which reads as
+/// `part`, in order.
+fn roles(resistor: &Resistor, part: &Resistance) -> Vec<(String, String)> {
+    let digit = |d: Digit| <Digit as Into<u8>>::into(d).to_string();
+    let mut roles = vec![
+        (String::from("digit 1"), digit(resistor.first())),
+        (String::from("digit 2"), digit(resistor.second())),
+    ];
+    if let Some(third) = resistor.third() {
+        roles.push((String::from("digit 3"), digit(third)));
     }
-}
-
-impl BandGroup {
-    fn ascii(&self) -> String {
-        match self {
-            Self::R3(b0, b1, b2) => format!("-[{},{},{}    ]-", b0.ascii(), b1.ascii(), b2.ascii()),
-            Self::R4(b0, b1, b2, b3) => format!(
-                "-[{},{},{}  {} ]-",
-                b0.ascii(),
-                b1.ascii(),
-                b2.ascii(),
-                b3.ascii()
-            ),
-            Self::R5(b0, b1, b2, b3, b4) => format!(
-                "-[{},{},{},{} {} ]-",
-                b0.ascii(),
-                b1.ascii(),
-                b2.ascii(),
-                b3.ascii(),
-                b4.ascii()
-            ),
-            Self::R6(b0, b1, b2, b3, b4, b5) => format!(
-                "-[{},{},{},{} {},{}]-",
-                b0.ascii(),
-                b1.ascii(),
-                b2.ascii(),
-                b3.ascii(),
-                b4.ascii(),
-                b5.ascii()
-            ),
-        }
+    let exp: i8 = resistor.multiplier().into();
+    let factor = match exp {
+        -3 => String::from("0.001"),
+        -2 => String::from("0.01"),
+        -1 => String::from("0.1"),
+        // group by metric prefix (ex: 10k)
+        e => format!(
+            "{}{}",
+            10_u32.pow(e as u32 % 3),
+            ["", "k", "M", "G"][e as usize / 3]
+        ),
+    };
+    roles.push((String::from("multiplier"), format!("×{}", factor)));
+    if resistor.tolerance() != Tolerance::Default {
+        roles.push((String::from("tolerance"), format!("±{}%", part.tolerance())));
     }
-}
-
-impl Display for BandGroup {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(
-            f,
-            "{}",
-            (match self {
-                Self::R3(b0, b1, b2) => format!("-[{}{}{}    ]-", b0, b1, b2),
-                Self::R4(b0, b1, b2, b3) => format!("-[{}{}{}  {} ]-", b0, b1, b2, b3),
-                Self::R5(b0, b1, b2, b3, b4) => format!("-[{}{}{}{} {} ]-", b0, b1, b2, b3, b4),
-                Self::R6(b0, b1, b2, b3, b4, b5) =>
-                    format!("-[{}{}{}{} {}{}]-", b0, b1, b2, b3, b4, b5),
-            })
-        )
-    }
-}
-
-#[derive(Debug, PartialEq)]
-enum BandLength {
-    L3,
-    L4,
-    L5,
-    L6,
-}
-
-impl From<usize> for BandLength {
-    fn from(f: usize) -> Self {
-        match f {
-            3 => Self::L3,
-            4 => Self::L4,
-            5 => Self::L5,
-            6 => Self::L6,
-            _ => panic!("unsupported band length {}", f),
-        }
-    }
-}
-
-impl Into<usize> for BandLength {
-    fn into(self) -> usize {
-        match self {
-            Self::L3 => 3,
-            Self::L4 => 4,
-            Self::L5 => 5,
-            Self::L6 => 6,
-        }
-    }
-}
-
-/// Minimum support number of colors for a resistor.
-const MIN_CODE_LEN: BandLength = BandLength::L3;
-
-/// Maximum number of colors for a resistor.
-const MAX_CODE_LEN: BandLength = BandLength::L6;
-
-#[derive(Debug, PartialEq)]
-struct Resistor {
-    first: Digit,
-    second: Digit,
-    third: Option<Digit>,
-    multiplier: Multiplier,
-    tolerance: Tolerance,
-    temp_coeff: Option<TempCoeff>,
-}
-
-impl Resistor {
-    fn decode(vec: Vec<Band>) -> Result<Self, BandError> {
-        // reverse to use the `pop` method
-        let mut vec = vec;
-        vec.reverse();
-        // capture the state of how many bands are specified
-        let band_count = vec.len();
-        if band_count >= MIN_CODE_LEN.into() && band_count <= MAX_CODE_LEN.into() {
-            Ok(Self {
-                first: Digit::from_band(&vec.pop().unwrap())?,
-                second: Digit::from_band(&vec.pop().unwrap())?,
-                third: {
-                    match BandLength::from(band_count) {
-                        BandLength::L3 | BandLength::L4 => None,
-                        BandLength::L5 | BandLength::L6 => {
-                            Some(Digit::from_band(&vec.pop().unwrap())?)
-                        }
-                    }
-                },
-                multiplier: Multiplier::from_band(&vec.pop().unwrap())?,
-                tolerance: match vec.pop() {
-                    Some(b) => Tolerance::from_band(&b)?,
-                    None => Tolerance::Default,
-                },
-                temp_coeff: match vec.pop() {
-                    Some(b) => Some(TempCoeff::from_band(&b)?),
-                    None => None,
-                },
-            })
-        } else {
-            Err(BandError::OutOfRange(band_count))
-        }
-    }
-
-    #[allow(dead_code)]
-    fn size(&self) -> BandLength {
-        if self.tolerance != Tolerance::Default {
-            if self.third.is_some() {
-                if self.temp_coeff.is_some() {
-                    BandLength::L6
-                } else {
-                    BandLength::L5
-                }
-            } else {
-                BandLength::L4
-            }
-        } else {
-            BandLength::L3
-        }
+    if let Some(t) = part.temp_coeff() {
+        roles.push((String::from("temp. coeff."), format!("{} ppm/K", t)));
     }
+    roles
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[test]
-    fn ut_raw_resistance_3_band() {
-        let r = Resistor {
-            first: Digit::Brown,
-            second: Digit::Black,
-            third: None,
-            multiplier: Multiplier::Brown,
-            tolerance: Tolerance::Default,
-            temp_coeff: None,
-        };
-
-        assert_eq!(Ohm::calculate_raw(&r), 100.0);
-        assert_eq!(r.size(), BandLength::L3);
+    /// Interprets the command-line `args` as given after the program's name.
+    fn interpret(args: &[&str]) -> Ohm {
+        let argv = std::iter::once("ohm").chain(args.iter().copied());
+        let mut cli = Cli::new().parse(argv.map(String::from)).save();
+        // the developer's own configuration file must not leak into the tests
+        Ohm::interpret_with(&mut cli, Config::default(), None).unwrap()
     }
 
     #[test]
-    fn ut_raw_resistance_4_band() {
-        let r = Resistor {
-            first: Digit::Brown,
-            second: Digit::Red,
-            third: None,
-            multiplier: Multiplier::Green,
-            tolerance: Tolerance::Gold,
-            temp_coeff: None,
-        };
-
-        assert_eq!(Ohm::calculate_raw(&r), 1200_000.0);
-        assert_eq!(r.size(), BandLength::L4);
+    fn ut_interpret() {
+        let ohm = interpret(&[
+            "y",
+            "v",
+            "r",
+            "au",
+            "--no-color",
+            "--iec",
+            "--accessible",
+            "--caption",
+            "--color",
+            "16",
+            "--body",
+            "metal",
+            "--lang",
+            "de",
+            "--series",
+            "E12",
+            "--standard",
+            "iec60062-2016",
+            "--error",
+            "2%",
+            "--tol",
+            "1%",
+            "--inventory",
+            "stock.toml",
+            "--add",
+            "2",
+            "--remove",
+            "1",
+            "--output",
+            "r.svg",
+            "--width",
+            "800",
+            "--measured",
+            "4k68",
+            "--temp",
+            "30",
+            "--alias",
+            "au=gold",
+        ]);
+        assert!(ohm.no_color && ohm.iec && ohm.accessible && ohm.caption);
+        assert!(!ohm.list);
+        assert_eq!(ohm.color, Some(ColorSupport::Ansi16));
+        assert_eq!(ohm.lang, Some(Locale::De));
+        assert_eq!(ohm.standard, Some(Standard::IEC_60062_2016));
+        assert_eq!(ohm.add, Some(2));
+        assert_eq!(ohm.output, Some(PathBuf::from("r.svg")));
+        assert_eq!(ohm.aliases, vec![Alias::new("au", Band::Gold).unwrap()]);
+        assert_eq!(ohm.task(), (None, &ohm.args.clone().unwrap()[..]));
+        assert_eq!(ohm.args.unwrap(), vec!["y", "v", "r", "au"]);
+        // options that select another task
+        let ohm = interpret(&[
+            "-l", "--find", "4k75", "--stock", "1k..10k", "--bom", "b.csv",
+        ]);
+        assert!(ohm.list && ohm.find.is_some() && ohm.stock.is_some() && ohm.bom.is_some());
+        assert!(ohm.args.is_none());
+        // a task named before its arguments
+        let ohm = interpret(&["substitute", "6k8", "--tol", "1%"]);
+        assert_eq!(
+            ohm.task(),
+            (Some(Task::Substitute), &[String::from("6k8")][..])
+        );
+        let ohm = interpret(&[
+            "render", "n", "k", "r", "d", "-o", "r.png", "--width", "800",
+        ]);
+        assert_eq!(ohm.task().0, Some(Task::Render));
+        assert_eq!(ohm.task().1.len(), 4);
+        assert_eq!(ohm.width, Some(800));
+        let ohm = interpret(&["check", "n", "k", "r", "d", "--measured", "1013"]);
+        assert_eq!(ohm.task().0, Some(Task::Check));
+        assert_eq!(ohm.task().1.len(), 4);
+        assert_eq!(ohm.measured, Some("1013".parse().unwrap()));
     }
 
     #[test]
-    fn ut_raw_resistance_5_band() {
-        let r = Resistor {
-            first: Digit::Red,
-            second: Digit::Red,
-            third: Some(Digit::Black),
-            multiplier: Multiplier::Black,
-            tolerance: Tolerance::Gold,
-            temp_coeff: None,
-        };
+    fn ut_identify_part() {
+        let ohm = interpret(&["--no-color"]);
+        assert_eq!(
+            ohm.identify_part(dec!(4700), ESeries::E24),
+            "-[yellow,violet,red  gold ]-"
+        );
+        // parts beyond the reach of the multiplier bands are marked
+        assert_eq!(ohm.identify_part(dec!(0.0102), ESeries::E96), "-");
+        assert_eq!(ohm.identify_part(dec!(1e12), ESeries::E24), "-");
+    }
 
-        assert_eq!(Ohm::calculate_raw(&r), 220.0);
-        assert_eq!(r.size(), BandLength::L5);
+    #[test]
+    fn ut_identify_ascii() {
+        let bands = vec![Band::Yellow, Band::Violet, Band::Red, Band::Gold];
+        // the bands are painted as spaces on the body, so any character set
+        // can show them
+        let drawn = interpret(&["--color", "16"]).identify(bands.clone());
+        assert!(drawn.is_ascii());
+        let mut text = String::new();
+        let mut chars = drawn.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '\x1b' => while chars.next().is_some_and(|c| c != 'm') {},
+                c => text.push(c),
+            }
+        }
+        assert_eq!(text, "-[         ]-");
+        let named = interpret(&["--no-color"]).identify(bands);
+        assert_eq!(named, "-[yellow,violet,red  gold ]-");
     }
 
     #[test]
-    fn ut_raw_resistance_6_band() {
-        let r = Resistor {
-            first: Digit::Red,
-            second: Digit::Violet,
-            third: Some(Digit::Yellow),
-            multiplier: Multiplier::Black,
-            tolerance: Tolerance::Red,
-            temp_coeff: Some(TempCoeff::Black),
-        };
+    fn ut_role_table() {
+        let ohm = interpret(&["--accessible", "--no-color", "--lang", "ja"]);
+        let bands = [
+            Band::Brown,
+            Band::Black,
+            Band::Black,
+            Band::Red,
+            Band::Brown,
+            Band::Red,
+        ];
+        let resistor = Resistor::decode(&bands).unwrap();
+        let part = ohm.compute(&resistor).unwrap();
+        let table = ohm.role_table(&bands, &resistor, &part);
+        let rows: Vec<&str> = table.lines().collect();
+        assert_eq!(rows[0], "Band  Mark  Role          Value       Color");
+        // the names of the colors cannot push the other columns out of line
+        assert!(rows[1].starts_with("1     "));
+        assert!(rows[1].ends_with("digit 1       1           茶"));
+        assert!(rows[6].ends_with("temp. coeff.  50 ppm/K    赤"));
+    }
 
-        assert_eq!(Ohm::calculate_raw(&r), 274.0);
-        assert_eq!(r.size(), BandLength::L6);
+    #[test]
+    fn ut_roles() {
+        let r = Resistor::decode(&[Band::Yellow, Band::Violet, Band::Red, Band::Gold]).unwrap();
+        let values: Vec<String> = roles(&r, &r.resistance().into())
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        assert_eq!(values, vec!["4", "7", "×100", "±5%"]);
+        let r = Resistor::decode(&[Band::Brown, Band::Black, Band::Silver]).unwrap();
+        assert_eq!(roles(&r, &r.resistance().into())[2].1, "×0.01");
+        let r = Resistor::decode(&[Band::Brown, Band::Black, Band::Blue]).unwrap();
+        assert_eq!(roles(&r, &r.resistance().into())[2].1, "×1M");
+        // the standard decides what the tolerance band stands for
+        let r = Resistor::decode(&[
+            Band::Brown,
+            Band::Black,
+            Band::Black,
+            Band::Brown,
+            Band::Grey,
+            Band::Red,
+        ])
+        .unwrap();
+        let part = r.resistance_with(&Standard::IEC_60062_2016).unwrap().into();
+        let values: Vec<String> = roles(&r, &part).into_iter().map(|(_, v)| v).collect();
+        assert_eq!(values[4..], ["±0.01%", "50 ppm/K"]);
     }
 }
@@ -0,0 +1,400 @@
+use crate::band::Band;
+use crate::color::{self, Rgb};
+use std::path::Path;
+use thiserror::Error;
+
+/// Minimum color distance between a pixel and the background for the pixel to
+/// belong to the resistor.
+const BACKGROUND_THRESHOLD: f64 = 48.0;
+
+/// Minimum color distance between a column of the body and the body's own
+/// color for the column to belong to a stripe.
+const STRIPE_THRESHOLD: f64 = 40.0;
+
+/// Spread of the perceptual color differences used to weigh how well a stripe
+/// matches each band.
+const COLOR_SPREAD: f64 = 6.0;
+
+/// Minimum likelihood of the best match for a stripe to not be ambiguous.
+const CONFIDENT: f64 = 0.6;
+
+/// Minimum likelihood of a band for it to be listed as a candidate.
+const CANDIDATE: f64 = 0.1;
+
+/// Maximum number of candidates kept for each stripe.
+const MAX_CANDIDATES: usize = 3;
+
+/// The color of a pixel as red, green, and blue components.
+type Pixel = [f64; 3];
+
+fn distance(a: Pixel, b: Pixel) -> f64 {
+    a.iter()
+        .zip(b.iter())
+        .map(|(x, y)| (x - y).powi(2))
+        .sum::<f64>()
+        .sqrt()
+}
+
+/// Computes the median of each channel of `colors`.
+fn median(colors: &[Pixel]) -> Pixel {
+    let mut out = [0.0; 3];
+    for (i, c) in out.iter_mut().enumerate() {
+        let mut channel: Vec<f64> = colors.iter().map(|p| p[i]).collect();
+        channel.sort_by(|a, b| a.total_cmp(b));
+        *c = channel.get(channel.len() / 2).copied().unwrap_or_default();
+    }
+    out
+}
+
+/// Computes the average of each channel of `colors`.
+fn mean(colors: &[Pixel]) -> Pixel {
+    let mut out = [0.0; 3];
+    for p in colors {
+        for i in 0..3 {
+            out[i] += p[i] / colors.len() as f64;
+        }
+    }
+    out
+}
+
+/// Finds the longest run of consecutive indices where `keep` is true.
+fn longest_run(len: usize, keep: impl Fn(usize) -> bool) -> Option<(usize, usize)> {
+    let mut best: Option<(usize, usize)> = None;
+    let mut start = None;
+    for i in 0..=len {
+        match (i < len && keep(i), start) {
+            (true, None) => start = Some(i),
+            (false, Some(s)) => {
+                if best.is_none_or(|(b0, b1)| i - s > b1 - b0 + 1) {
+                    best = Some((s, i - 1));
+                }
+                start = None;
+            }
+            _ => (),
+        }
+    }
+    best
+}
+
+/// A colored stripe found on the body of a resistor.
+#[derive(Debug, PartialEq)]
+pub struct Stripe {
+    start: usize,
+    end: usize,
+    candidates: Vec<(Band, f64)>,
+}
+
+impl Stripe {
+    /// Matches the `color` of the stripe against every band, keeping the most
+    /// likely bands.
+    fn new(start: usize, end: usize, color: Pixel) -> Self {
+        let rgb = Rgb(
+            color[0].round() as u8,
+            color[1].round() as u8,
+            color[2].round() as u8,
+        );
+        let weights: Vec<(Band, f64)> = color::rank(rgb)
+            .into_iter()
+            .map(|(b, d)| (b, (-(d * d) / (2.0 * COLOR_SPREAD * COLOR_SPREAD)).exp()))
+            .collect();
+        let total: f64 = weights.iter().map(|(_, w)| w).sum();
+        let mut candidates: Vec<(Band, f64)> = weights
+            .into_iter()
+            .map(|(b, w)| match total > 0.0 {
+                true => (b, w / total),
+                false => (b, 0.0),
+            })
+            .collect();
+        candidates.sort_by(|(_, x), (_, y)| y.total_cmp(x));
+        // always keep the best match, even when it is a poor one
+        let keep = candidates
+            .iter()
+            .skip(1)
+            .take_while(|(_, p)| *p >= CANDIDATE)
+            .count()
+            + 1;
+        candidates.truncate(keep.min(MAX_CANDIDATES));
+        Self {
+            start,
+            end,
+            candidates,
+        }
+    }
+
+    /// Returns the band that best matches the stripe.
+    pub fn band(&self) -> &Band {
+        &self.candidates[0].0
+    }
+
+    /// Returns the likelihood (between 0 and 1) that the best match is correct.
+    pub fn confidence(&self) -> f64 {
+        self.candidates[0].1
+    }
+
+    /// Checks if the stripe could reasonably be more than one band.
+    pub fn is_ambiguous(&self) -> bool {
+        self.confidence() < CONFIDENT
+    }
+
+    /// Lists the bands the stripe could be, from most to least likely.
+    pub fn candidates(&self) -> &[(Band, f64)] {
+        &self.candidates
+    }
+}
+
+/// A photo of a resistor lying horizontally on a neutral background.
+#[derive(Debug, PartialEq)]
+pub struct Photo {
+    width: usize,
+    height: usize,
+    pixels: Vec<Pixel>,
+}
+
+impl Photo {
+    /// Reads a PNG or JPEG image from `path`.
+    pub fn load(path: &Path) -> Result<Self, PhotoError> {
+        Self::decode(&std::fs::read(path)?)
+    }
+
+    /// Reads a PNG or JPEG image from its encoded `data`.
+    pub fn decode(data: &[u8]) -> Result<Self, PhotoError> {
+        if data.starts_with(b"\x89PNG") {
+            Self::decode_png(data)
+        } else if data.starts_with(&[0xFF, 0xD8]) {
+            Self::decode_jpeg(data)
+        } else {
+            Err(PhotoError::UnsupportedFormat)
+        }
+    }
+
+    fn decode_png(data: &[u8]) -> Result<Self, PhotoError> {
+        let mut decoder = png::Decoder::new(data);
+        decoder.set_transformations(png::Transformations::normalize_to_color8());
+        let mut reader = decoder.read_info()?;
+        let mut buf = vec![0; reader.output_buffer_size()];
+        let info = reader.next_frame(&mut buf)?;
+        let channels = info.color_type.samples();
+        let pixels = buf[..info.buffer_size()]
+            .chunks_exact(channels)
+            .map(|p| {
+                let (color, alpha) = match channels {
+                    1 => ([p[0]; 3], 255),
+                    2 => ([p[0]; 3], p[1]),
+                    3 => ([p[0], p[1], p[2]], 255),
+                    _ => ([p[0], p[1], p[2]], p[3]),
+                };
+                // place any transparent areas over a white background
+                let a = alpha as f64 / 255.0;
+                color.map(|c| c as f64 * a + 255.0 * (1.0 - a))
+            })
+            .collect();
+        Ok(Self {
+            width: info.width as usize,
+            height: info.height as usize,
+            pixels,
+        })
+    }
+
+    fn decode_jpeg(data: &[u8]) -> Result<Self, PhotoError> {
+        let mut decoder = jpeg_decoder::Decoder::new(data);
+        let buf = decoder.decode()?;
+        let info = decoder.info().ok_or(PhotoError::UnsupportedFormat)?;
+        let pixels = match info.pixel_format {
+            jpeg_decoder::PixelFormat::L8 => buf.iter().map(|l| [*l as f64; 3]).collect(),
+            jpeg_decoder::PixelFormat::RGB24 => buf
+                .chunks_exact(3)
+                .map(|p| [p[0] as f64, p[1] as f64, p[2] as f64])
+                .collect(),
+            _ => return Err(PhotoError::UnsupportedFormat),
+        };
+        Ok(Self {
+            width: info.width as usize,
+            height: info.height as usize,
+            pixels,
+        })
+    }
+
+    fn pixel(&self, x: usize, y: usize) -> Pixel {
+        self.pixels[y * self.width + x]
+    }
+
+    /// Estimates the background color from the pixels along the image's edges.
+    fn background(&self) -> Pixel {
+        let mut edge = Vec::new();
+        for x in 0..self.width {
+            edge.push(self.pixel(x, 0));
+            edge.push(self.pixel(x, self.height - 1));
+        }
+        for y in 0..self.height {
+            edge.push(self.pixel(0, y));
+            edge.push(self.pixel(self.width - 1, y));
+        }
+        median(&edge)
+    }
+
+    /// Locates the stripes painted on the resistor's body from left to right.
+    ///
+    /// The body is found as the widest part of the resistor, which sets it
+    /// apart from its thin leads. The stripes are then found by sampling a
+    /// strip along the middle of the body and looking for the columns that
+    /// differ from the body's own color.
+    pub fn stripes(&self) -> Result<Vec<Stripe>, PhotoError> {
+        if self.width == 0 || self.height == 0 {
+            return Err(PhotoError::NoResistor);
+        }
+        let bg = self.background();
+        let is_part = |x: usize, y: usize| distance(self.pixel(x, y), bg) > BACKGROUND_THRESHOLD;
+
+        // the body spans the columns with the most of the resistor in them
+        let columns: Vec<usize> = (0..self.width)
+            .map(|x| (0..self.height).filter(|y| is_part(x, *y)).count())
+            .collect();
+        let tallest = columns.iter().copied().max().unwrap_or(0);
+        if tallest < self.height / 20 + 1 {
+            return Err(PhotoError::NoResistor);
+        }
+        let (x0, x1) =
+            longest_run(self.width, |x| columns[x] * 2 >= tallest).ok_or(PhotoError::NoResistor)?;
+        let (y0, y1) = longest_run(self.height, |y| {
+            (x0..=x1).filter(|x| is_part(*x, y)).count() * 2 > x1 - x0
+        })
+        .ok_or(PhotoError::NoResistor)?;
+        if x1 - x0 < 10 || y1 - y0 < 3 {
+            return Err(PhotoError::NoResistor);
+        }
+
+        // sample the color along a strip through the middle of the body
+        let mid = (y0 + y1) / 2;
+        let half = ((y1 - y0) / 8).max(1);
+        let rows = mid - half..=mid + half;
+        let profile: Vec<Option<Pixel>> = (x0..=x1)
+            .map(|x| {
+                let colors: Vec<Pixel> = rows
+                    .clone()
+                    .filter(|y| is_part(x, *y))
+                    .map(|y| self.pixel(x, y))
+                    .collect();
+                match colors.len() * 2 > rows.clone().count() {
+                    true => Some(mean(&colors)),
+                    false => None,
+                }
+            })
+            .collect();
+        let body = median(&profile.iter().flatten().copied().collect::<Vec<Pixel>>());
+
+        // group the columns that stand out from the body into stripes
+        let min_width = ((x1 - x0) / 100).max(2);
+        let mut stripes = Vec::new();
+        let mut start = None;
+        for (i, color) in profile.iter().chain(std::iter::once(&None)).enumerate() {
+            let marked = color.is_some_and(|c| distance(c, body) > STRIPE_THRESHOLD);
+            match (marked, start) {
+                (true, None) => start = Some(i),
+                (false, Some(s)) => {
+                    if i - s >= min_width {
+                        // avoid the blended colors at the stripe's edges
+                        let quarter = (i - s) / 4;
+                        let inner: Vec<Pixel> = profile[s + quarter..i - quarter]
+                            .iter()
+                            .flatten()
+                            .copied()
+                            .collect();
+                        stripes.push(Stripe::new(x0 + s, x0 + i - 1, mean(&inner)));
+                    }
+                    start = None;
+                }
+                _ => (),
+            }
+        }
+        Ok(stripes)
+    }
+}
+
+/// Lists the sequences of bands the `stripes` could be read as, from most to
+/// least likely, in both directions.
+///
+/// The direction that leaves the widest gap before the last band is tried
+/// first, since the tolerance band is usually set apart from the others.
+pub fn readings(stripes: &[Stripe]) -> Vec<Vec<Band>> {
+    let mut combos: Vec<(Vec<Band>, f64)> = vec![(Vec::new(), 1.0)];
+    for s in stripes {
+        combos = combos
+            .into_iter()
+            .flat_map(|(seq, p)| {
+                s.candidates().iter().map(move |(b, q)| {
+                    let mut seq = seq.clone();
+                    seq.push(b.clone());
+                    (seq, p * q)
+                })
+            })
+            .collect();
+    }
+    combos.sort_by(|(_, x), (_, y)| y.total_cmp(x));
+
+    let gap = |i: usize| stripes[i + 1].start.saturating_sub(stripes[i].end);
+    let reversed_first = match stripes.len() {
+        0 | 1 => false,
+        n => gap(0) > gap(n - 2),
+    };
+    combos
+        .into_iter()
+        .flat_map(|(seq, _)| {
+            let mut rev = seq.clone();
+            rev.reverse();
+            match reversed_first {
+                true => [rev, seq],
+                false => [seq, rev],
+            }
+        })
+        .collect()
+}
+
+#[derive(Error, Debug)]
+pub enum PhotoError {
+    #[error("unsupported image format (expects png or jpeg)")]
+    UnsupportedFormat,
+    #[error("failed to find a resistor in the photo")]
+    NoResistor,
+    #[error("found {0} stripes on the resistor but expects between 3 and 6")]
+    StripeCount(usize),
+    #[error("{0}")]
+    Png(#[from] png::DecodingError),
+    #[error("{0}")]
+    Jpeg(#[from] jpeg_decoder::Error),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::color::Body;
+    use crate::group::BandGroup;
+    use crate::render;
+
+    #[test]
+    fn ut_stripes_from_drawing() {
+        let bands = vec![Band::Yellow, Band::Violet, Band::Red, Band::Gold];
+        let data = render::png(&BandGroup::from(bands.clone()), Body::Carbon, None, 400).unwrap();
+        let stripes = Photo::decode(&data).unwrap().stripes().unwrap();
+        let found: Vec<Band> = stripes.iter().map(|s| s.band().clone()).collect();
+        assert_eq!(found, bands);
+        assert!(stripes.iter().all(|s| !s.is_ambiguous()));
+        // the tolerance band is on the right, so read left to right first
+        assert_eq!(readings(&stripes)[0], bands);
+    }
+
+    #[test]
+    fn ut_ambiguous_stripe() {
+        // halfway between red and orange
+        let s = Stripe::new(0, 1, [255.0, 82.0, 0.0]);
+        assert!(s.is_ambiguous());
+        let bands: Vec<&Band> = s.candidates().iter().map(|(b, _)| b).collect();
+        assert!(bands.contains(&&Band::Red) && bands.contains(&&Band::Orange));
+    }
+
+    #[test]
+    fn ut_not_an_image() {
+        assert!(Photo::decode(b"resistor").is_err());
+    }
+}
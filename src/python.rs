@@ -0,0 +1,268 @@
+use crate::band::{self, Band, Tolerance};
+use crate::eseries::ESeries;
+use crate::ohm::Precision;
+use crate::resistance::Resistance;
+use crate::value::{Percent, Value};
+use ohm_core::Standard;
+use pyo3::create_exception;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+create_exception!(
+    ohm,
+    BandError,
+    PyValueError,
+    "The bands do not form a color code."
+);
+create_exception!(
+    ohm,
+    InvalidCodeError,
+    BandError,
+    "A band is not a known color."
+);
+create_exception!(
+    ohm,
+    OutOfRangeError,
+    BandError,
+    "The number of bands is not between 3 and 6."
+);
+create_exception!(
+    ohm,
+    UnsupportedBandError,
+    BandError,
+    "A band is not allowed in its position."
+);
+create_exception!(
+    ohm,
+    ReversedBandOrderError,
+    BandError,
+    "The bands are in reverse order."
+);
+create_exception!(
+    ohm,
+    UnrepresentableError,
+    BandError,
+    "A value cannot be marked with bands."
+);
+
+/// Raises the Python exception that matches `e`.
+fn band_error(e: band::BandError) -> PyErr {
+    let message = e.to_string();
+    match e {
+        band::BandError::InvalidCode => InvalidCodeError::new_err(message),
+        band::BandError::OutOfRange(_) => OutOfRangeError::new_err(message),
+        band::BandError::UnsupportedBand(_, _) => UnsupportedBandError::new_err(message),
+        band::BandError::ReversedBandOrder(_, _) => ReversedBandOrderError::new_err(message),
+        band::BandError::Unrepresentable(_) => UnrepresentableError::new_err(message),
+    }
+}
+
+/// Raises the Python exception that matches an error from writing bands.
+fn encode_error(e: band::EncodeError) -> PyErr {
+    match e {
+        band::EncodeError::Band(e) => band_error(e),
+        e => UnrepresentableError::new_err(e.to_string()),
+    }
+}
+
+/// Raises a `ValueError` for any other error.
+fn value_error(e: impl std::error::Error) -> PyErr {
+    PyValueError::new_err(e.to_string())
+}
+
+/// A resistance along with the range allowed by its tolerance.
+#[pyclass(name = "Resistance", module = "ohm", frozen, eq)]
+#[derive(Debug, PartialEq)]
+struct PyResistance(Resistance);
+
+#[pymethods]
+impl PyResistance {
+    #[new]
+    #[pyo3(signature = (raw, tolerance = Precision::from(5)))]
+    fn new(raw: Precision, tolerance: Precision) -> Self {
+        Self(Resistance::new(raw, tolerance, None))
+    }
+
+    /// Ideal value in Ohms.
+    #[getter]
+    fn raw(&self) -> Precision {
+        self.0.raw()
+    }
+
+    /// Allowed tolerance as a percentage.
+    #[getter]
+    fn tolerance(&self) -> Precision {
+        self.0.tolerance()
+    }
+
+    /// Lowest value in Ohms allowed by the tolerance.
+    #[getter]
+    fn minimum(&self) -> Precision {
+        self.0.minimum()
+    }
+
+    /// Highest value in Ohms allowed by the tolerance.
+    #[getter]
+    fn maximum(&self) -> Precision {
+        self.0.maximum()
+    }
+
+    /// Temperature coefficient in ppm/K, if marked.
+    #[getter]
+    fn temp_coeff(&self) -> Option<u8> {
+        self.0.temp_coeff()
+    }
+
+    fn __str__(&self) -> String {
+        self.0.to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Resistance(raw=Decimal('{}'), tolerance=Decimal('{}'))",
+            self.0.raw(),
+            self.0.tolerance()
+        )
+    }
+}
+
+/// Looks up the color code tables named `standard` (ex: `iec60062-2016`),
+/// using the classic tables when it is not given.
+fn standard(standard: Option<&str>) -> PyResult<Standard> {
+    standard.map_or(Ok(Standard::default()), |s| s.parse().map_err(value_error))
+}
+
+/// Reads the bands, listed from left to right, as a resistance under the
+/// color code `standard` when given.
+#[pyfunction]
+#[pyo3(signature = (bands, standard = None))]
+fn decode(bands: Vec<String>, standard: Option<&str>) -> PyResult<PyResistance> {
+    let standard = self::standard(standard)?;
+    let bands = bands
+        .iter()
+        .map(|s| band::parse(s))
+        .collect::<Result<Vec<Band>, _>>()
+        .map_err(band_error)?;
+    let resistor = band::read(&bands).map_err(band_error)?;
+    let resistance = resistor.resistance_with(&standard).map_err(band_error)?;
+    Ok(PyResistance(resistance.into()))
+}
+
+/// Writes a resistance (ex: `4k7`) as the names of its bands, ending with the
+/// band for the `tolerance` (ex: `5%`) under the color code `standard` when
+/// given.
+#[pyfunction]
+#[pyo3(signature = (value, tolerance = None, standard = None))]
+fn encode(value: &str, tolerance: Option<&str>, standard: Option<&str>) -> PyResult<Vec<String>> {
+    let standard = self::standard(standard)?;
+    let value: Value = value.parse().map_err(value_error)?;
+    let tolerance = match tolerance {
+        Some(t) => {
+            let percent: Percent = t.parse().map_err(value_error)?;
+            band::tolerance(percent.value(), &standard).map_err(encode_error)?
+        }
+        None => Tolerance::Default,
+    };
+    // prefer the shorter code
+    let bands = band::encode(value.ohms(), 2, tolerance)
+        .or_else(|_| band::encode(value.ohms(), 3, tolerance))
+        .map_err(encode_error)?;
+    Ok(bands.iter().map(|b| b.ascii().to_string()).collect())
+}
+
+/// Finds the most common E-series that includes the resistance (ex: `4k7`).
+#[pyfunction]
+fn series(value: &str) -> PyResult<Option<String>> {
+    let value: Value = value.parse().map_err(value_error)?;
+    Ok(ESeries::smallest_containing(value.ohms()).map(|s| s.to_string()))
+}
+
+/// Lists every value of the E-series `name` between `low` and `high`.
+#[pyfunction]
+fn series_values(name: &str, low: &str, high: &str) -> PyResult<Vec<Precision>> {
+    let series: ESeries = name.parse().map_err(value_error)?;
+    let low: Value = low.parse().map_err(value_error)?;
+    let high: Value = high.parse().map_err(value_error)?;
+    Ok(series.between(low.ohms(), high.ohms()))
+}
+
+#[pymodule]
+fn ohm(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyResistance>()?;
+    m.add_function(wrap_pyfunction!(decode, m)?)?;
+    m.add_function(wrap_pyfunction!(encode, m)?)?;
+    m.add_function(wrap_pyfunction!(series, m)?)?;
+    m.add_function(wrap_pyfunction!(series_values, m)?)?;
+    let py = m.py();
+    m.add("BandError", py.get_type::<BandError>())?;
+    m.add("InvalidCodeError", py.get_type::<InvalidCodeError>())?;
+    m.add("OutOfRangeError", py.get_type::<OutOfRangeError>())?;
+    m.add(
+        "UnsupportedBandError",
+        py.get_type::<UnsupportedBandError>(),
+    )?;
+    m.add(
+        "ReversedBandOrderError",
+        py.get_type::<ReversedBandOrderError>(),
+    )?;
+    m.add(
+        "UnrepresentableError",
+        py.get_type::<UnrepresentableError>(),
+    )?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+
+    #[test]
+    fn ut_python_bindings() {
+        Python::initialize();
+        Python::attach(|py| {
+            let module = PyModule::new(py, "ohm").unwrap();
+            ohm(&module).unwrap();
+            let r = decode(
+                vec![
+                    String::from("yellow"),
+                    String::from("violet"),
+                    String::from("red"),
+                    String::from("gold"),
+                ],
+                None,
+            )
+            .unwrap();
+            assert_eq!(r.raw(), dec!(4700));
+            assert_eq!(r.minimum(), dec!(4465));
+            // errors raise the matching exception
+            let err = decode(vec![String::from("red"), String::from("red")], None).unwrap_err();
+            assert!(err.is_instance_of::<OutOfRangeError>(py));
+            assert!(err.is_instance_of::<BandError>(py));
+            assert!(err.is_instance_of::<PyValueError>(py));
+            let err = encode("4k7", Some("7%"), None).unwrap_err();
+            assert!(err.is_instance_of::<UnrepresentableError>(py));
+            assert_eq!(
+                encode("4k7", Some("5%"), None).unwrap(),
+                vec!["yellow", "violet", "red", "gold"]
+            );
+            // the standard decides which band marks the tolerance
+            assert_eq!(
+                encode("10k", Some("0.05%"), Some("iec60062-2016")).unwrap(),
+                vec!["brown", "black", "orange", "orange"]
+            );
+            let r = decode(
+                ["n", "k", "k", "r", "o"].map(String::from).to_vec(),
+                Some("iec60062-2016"),
+            )
+            .unwrap();
+            assert_eq!(r.tolerance(), dec!(0.05));
+            assert!(encode("10k", None, Some("eia")).is_err());
+            assert_eq!(series("4k75").unwrap(), Some(String::from("E96")));
+            assert_eq!(
+                series_values("E6", "100", "220").unwrap(),
+                vec![dec!(100), dec!(150), dec!(220)]
+            );
+        });
+    }
+}
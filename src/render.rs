@@ -0,0 +1,571 @@
+use crate::band::Band;
+use crate::color::Body;
+use crate::group::BandGroup;
+use std::path::Path;
+use thiserror::Error;
+
+/// Width of the drawing in user units.
+const WIDTH: u32 = 400;
+
+/// Height of the drawing in user units.
+const HEIGHT: u32 = 120;
+
+/// Height of the strip below the drawing that holds the caption.
+const CAPTION_HEIGHT: u32 = 24;
+
+/// Thickness of the wire leads.
+const LEAD_WIDTH: u32 = 6;
+
+/// Horizontal extent of the resistor body.
+const BODY_LEFT: u32 = 80;
+const BODY_RIGHT: u32 = 320;
+
+/// Width of the wider caps at each end of the body.
+const CAP_WIDTH: u32 = 56;
+
+/// Height of the caps at each end of the body.
+const CAP_HEIGHT: u32 = 76;
+
+/// Corner radius of the caps at each end of the body.
+const CAP_RADIUS: u32 = CAP_HEIGHT / 3;
+
+/// Height of the narrower middle section of the body.
+const WAIST_HEIGHT: u32 = 60;
+
+/// Horizontal extent of the region of the body that holds the band slots.
+const SLOTS_LEFT: u32 = 94;
+const SLOTS_RIGHT: u32 = 306;
+
+/// Fraction of a slot covered by its band.
+const BAND_FILL: f64 = 0.55;
+
+/// Range of pixel widths accepted for raster images.
+const MIN_PIXELS: u32 = 100;
+const MAX_PIXELS: u32 = 8000;
+
+/// Number of samples taken along each axis of a pixel to smooth edges.
+const SUPERSAMPLE: u32 = 4;
+
+const LEAD_COLOR: (u8, u8, u8) = (0xA0, 0xA0, 0xA0);
+
+const CAPTION_COLOR: (u8, u8, u8) = (0x33, 0x33, 0x33);
+
+/// Vertical shading laid over the body as `(offset, white, opacity)` stops.
+const SHADE: [(f64, bool, f64); 3] = [(0.0, true, 0.45), (0.45, true, 0.0), (1.0, false, 0.35)];
+
+/// Computes the left edge and width of every band drawn on the body.
+fn bands(group: &BandGroup) -> Vec<(f64, f64, &Band)> {
+    let layout = group.layout();
+    let slot = (SLOTS_RIGHT - SLOTS_LEFT) as f64 / layout.len() as f64;
+    let width = slot * BAND_FILL;
+    layout
+        .iter()
+        .enumerate()
+        .filter_map(|(i, b)| {
+            let center = SLOTS_LEFT as f64 + slot * (i as f64 + 0.5);
+            b.map(|b| (center - width / 2.0, width, b))
+        })
+        .collect()
+}
+
+fn hex(rgb: (u8, u8, u8)) -> String {
+    format!("#{:02X}{:02X}{:02X}", rgb.0, rgb.1, rgb.2)
+}
+
+/// Escapes the characters that cannot appear in XML text.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// Draws the resistor with the color of its `body` as an SVG document, with
+/// an optional `caption` written below it.
+pub fn svg(group: &BandGroup, body: Body, caption: Option<&str>) -> String {
+    let mid = HEIGHT / 2;
+    let cap_y = mid - CAP_HEIGHT / 2;
+    let waist_y = mid - WAIST_HEIGHT / 2;
+    let height = match caption {
+        Some(_) => HEIGHT + CAPTION_HEIGHT,
+        None => HEIGHT,
+    };
+    let outline = format!(
+        "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\"/>\
+         <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" rx=\"{}\"/>\
+         <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\"/>",
+        BODY_LEFT,
+        cap_y,
+        CAP_WIDTH,
+        CAP_HEIGHT,
+        CAP_RADIUS,
+        BODY_RIGHT - CAP_WIDTH,
+        cap_y,
+        CAP_WIDTH,
+        CAP_HEIGHT,
+        CAP_RADIUS,
+        BODY_LEFT + CAP_WIDTH / 2,
+        waist_y,
+        BODY_RIGHT - BODY_LEFT - CAP_WIDTH,
+        WAIST_HEIGHT,
+    );
+    let mut doc = String::new();
+    doc.push_str(&format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {w} {h}\" width=\"{w}\" height=\"{h}\">\n",
+        w = WIDTH,
+        h = height
+    ));
+    doc.push_str(&format!("<title>{}</title>\n", group.ascii()));
+    doc.push_str("<defs>\n<linearGradient id=\"shade\" x1=\"0\" y1=\"0\" x2=\"0\" y2=\"1\">");
+    for (offset, white, opacity) in SHADE {
+        doc.push_str(&format!(
+            "<stop offset=\"{}\" stop-color=\"{}\" stop-opacity=\"{}\"/>",
+            offset,
+            match white {
+                true => "#FFFFFF",
+                false => "#000000",
+            },
+            opacity
+        ));
+    }
+    doc.push_str("</linearGradient>\n");
+    doc.push_str(&format!(
+        "<clipPath id=\"body\">{}</clipPath>\n</defs>\n",
+        outline
+    ));
+    // leads
+    doc.push_str(&format!(
+        "<line x1=\"0\" y1=\"{y}\" x2=\"{w}\" y2=\"{y}\" stroke=\"{}\" stroke-width=\"{}\" stroke-linecap=\"round\"/>\n",
+        hex(LEAD_COLOR),
+        LEAD_WIDTH,
+        y = mid,
+        w = WIDTH
+    ));
+    // body and bands
+    doc.push_str("<g clip-path=\"url(#body)\">\n");
+    doc.push_str(&format!(
+        "<rect x=\"0\" y=\"0\" width=\"{}\" height=\"{}\" fill=\"{}\"/>\n",
+        WIDTH,
+        HEIGHT,
+        hex(body.rgb())
+    ));
+    for (x, width, band) in bands(group) {
+        doc.push_str(&format!(
+            "<rect x=\"{:.2}\" y=\"0\" width=\"{:.2}\" height=\"{}\" fill=\"{}\"><title>{}</title></rect>\n",
+            x,
+            width,
+            HEIGHT,
+            hex(band.rgb()),
+            band.ascii()
+        ));
+    }
+    doc.push_str(&format!(
+        "<rect x=\"0\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"url(#shade)\"/>\n",
+        cap_y, WIDTH, CAP_HEIGHT
+    ));
+    doc.push_str("</g>\n");
+    if let Some(text) = caption {
+        doc.push_str(&format!(
+            "<text x=\"{}\" y=\"{}\" text-anchor=\"middle\" font-family=\"sans-serif\" font-size=\"14\" fill=\"{}\">{}</text>\n",
+            WIDTH / 2,
+            HEIGHT + CAPTION_HEIGHT / 2 + 5,
+            hex(CAPTION_COLOR),
+            escape(text)
+        ));
+    }
+    doc.push_str("</svg>\n");
+    doc
+}
+
+/// An RGBA color with components between 0 and 1.
+type Rgba = [f64; 4];
+
+fn rgba(rgb: (u8, u8, u8)) -> Rgba {
+    [
+        rgb.0 as f64 / 255.0,
+        rgb.1 as f64 / 255.0,
+        rgb.2 as f64 / 255.0,
+        1.0,
+    ]
+}
+
+/// Lays the color `top` with `opacity` over the color `bottom`.
+fn blend(bottom: Rgba, top: Rgba, opacity: f64) -> Rgba {
+    let mut out = bottom;
+    for i in 0..3 {
+        out[i] = bottom[i] * (1.0 - opacity) + top[i] * opacity;
+    }
+    out
+}
+
+/// Checks if the point (`x`, `y`) is within the rectangle with rounded corners
+/// of radius `r`.
+fn in_rounded_rect(x: f64, y: f64, left: f64, top: f64, w: f64, h: f64, r: f64) -> bool {
+    if x < left || x > left + w || y < top || y > top + h {
+        return false;
+    }
+    // distance past the straight edges into a corner
+    let dx = (left + r - x).max(x - (left + w - r)).max(0.0);
+    let dy = (top + r - y).max(y - (top + h - r)).max(0.0);
+    dx * dx + dy * dy <= r * r
+}
+
+/// Determines the color of the drawing at the point (`x`, `y`) in user units,
+/// which is transparent outside of the resistor.
+fn color_at(group_bands: &[(f64, f64, &Band)], body: Body, x: f64, y: f64) -> Rgba {
+    let mid = (HEIGHT / 2) as f64;
+    let cap_y = mid - (CAP_HEIGHT / 2) as f64;
+    let waist_y = mid - (WAIST_HEIGHT / 2) as f64;
+    let in_body = in_rounded_rect(
+        x,
+        y,
+        BODY_LEFT as f64,
+        cap_y,
+        CAP_WIDTH as f64,
+        CAP_HEIGHT as f64,
+        CAP_RADIUS as f64,
+    ) || in_rounded_rect(
+        x,
+        y,
+        (BODY_RIGHT - CAP_WIDTH) as f64,
+        cap_y,
+        CAP_WIDTH as f64,
+        CAP_HEIGHT as f64,
+        CAP_RADIUS as f64,
+    ) || in_rounded_rect(
+        x,
+        y,
+        (BODY_LEFT + CAP_WIDTH / 2) as f64,
+        waist_y,
+        (BODY_RIGHT - BODY_LEFT - CAP_WIDTH) as f64,
+        WAIST_HEIGHT as f64,
+        0.0,
+    );
+    if in_body {
+        let base = match group_bands.iter().find(|(l, w, _)| x >= *l && x <= l + w) {
+            Some((_, _, b)) => rgba(b.rgb()),
+            None => rgba(body.rgb()),
+        };
+        // interpolate the shading between its surrounding stops
+        let t = (y - cap_y) / CAP_HEIGHT as f64;
+        let (lo, hi) = match t < SHADE[1].0 {
+            true => (SHADE[0], SHADE[1]),
+            false => (SHADE[1], SHADE[2]),
+        };
+        let f = ((t - lo.0) / (hi.0 - lo.0)).clamp(0.0, 1.0);
+        let shade = [lo, hi].map(|(_, white, opacity)| {
+            let color = match white {
+                true => [1.0, 1.0, 1.0, 1.0],
+                false => [0.0, 0.0, 0.0, 1.0],
+            };
+            (color, opacity)
+        });
+        // fade between the stops by blending each in turn
+        let over = blend(base, shade[0].0, shade[0].1 * (1.0 - f));
+        blend(over, shade[1].0, shade[1].1 * f)
+    } else if (y - mid).abs() <= LEAD_WIDTH as f64 / 2.0 {
+        rgba(LEAD_COLOR)
+    } else {
+        [0.0; 4]
+    }
+}
+
+/// Writes `text` into the RGBA `pixels` of an image `width` pixels wide using
+/// a bitmap font, centered horizontally within the rows starting at `top`.
+fn write_text(pixels: &mut [u8], width: u32, top: u32, scale: u32, text: &str) {
+    let advance = (GLYPH_WIDTH + 1) * scale;
+    let span = text.chars().count() as u32 * advance;
+    let left = width.saturating_sub(span) / 2;
+    let (r, g, b) = CAPTION_COLOR;
+    for (i, c) in text.chars().enumerate() {
+        let columns = glyph(c);
+        for (col, bits) in columns.iter().enumerate() {
+            for row in 0..GLYPH_HEIGHT {
+                if bits & (1 << row) == 0 {
+                    continue;
+                }
+                let x0 = left + i as u32 * advance + col as u32 * scale;
+                let y0 = top + row * scale;
+                for y in y0..y0 + scale {
+                    for x in (x0..x0 + scale).filter(|x| *x < width) {
+                        let p = ((y * width + x) * 4) as usize;
+                        if let Some(px) = pixels.get_mut(p..p + 4) {
+                            px.copy_from_slice(&[r, g, b, 0xFF]);
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Draws the resistor with the color of its `body` as a PNG image `width`
+/// pixels wide, with an optional `caption` written below it.
+pub fn png(
+    group: &BandGroup,
+    body: Body,
+    caption: Option<&str>,
+    width: u32,
+) -> Result<Vec<u8>, RenderError> {
+    if !(MIN_PIXELS..=MAX_PIXELS).contains(&width) {
+        return Err(RenderError::InvalidWidth(width));
+    }
+    let unit = width as f64 / WIDTH as f64;
+    let drawing_height = (HEIGHT as f64 * unit).round() as u32;
+    // size the caption so it fits across the image
+    let scale = caption.map(|text| {
+        let chars = text.chars().count().max(1) as u32;
+        let fit = (width * 9 / 10) / (chars * (GLYPH_WIDTH + 1));
+        fit.clamp(
+            1,
+            (CAPTION_HEIGHT as f64 * unit) as u32 / (GLYPH_HEIGHT + 4) + 1,
+        )
+    });
+    let height = drawing_height + scale.map_or(0, |s| (GLYPH_HEIGHT + 4) * s);
+
+    let group_bands = bands(group);
+    let mut pixels = vec![0_u8; (width * height * 4) as usize];
+    let samples = (SUPERSAMPLE * SUPERSAMPLE) as f64;
+    for py in 0..drawing_height {
+        for px in 0..width {
+            let mut sum = [0.0; 4];
+            for sy in 0..SUPERSAMPLE {
+                for sx in 0..SUPERSAMPLE {
+                    let x = (px as f64 + (sx as f64 + 0.5) / SUPERSAMPLE as f64) / unit;
+                    let y = (py as f64 + (sy as f64 + 0.5) / SUPERSAMPLE as f64) / unit;
+                    let c = color_at(&group_bands, body, x, y);
+                    // weight by coverage so edges fade into transparency
+                    for i in 0..3 {
+                        sum[i] += c[i] * c[3];
+                    }
+                    sum[3] += c[3];
+                }
+            }
+            let p = ((py * width + px) * 4) as usize;
+            let alpha = sum[3] / samples;
+            for i in 0..3 {
+                let v = match sum[3] > 0.0 {
+                    true => sum[i] / sum[3],
+                    false => 0.0,
+                };
+                pixels[p + i] = (v * 255.0).round() as u8;
+            }
+            pixels[p + 3] = (alpha * 255.0).round() as u8;
+        }
+    }
+    if let (Some(text), Some(scale)) = (caption, scale) {
+        write_text(&mut pixels, width, drawing_height + 2 * scale, scale, text);
+    }
+
+    let mut data = Vec::new();
+    let mut encoder = ::png::Encoder::new(&mut data, width, height);
+    encoder.set_color(::png::ColorType::Rgba);
+    encoder.set_depth(::png::BitDepth::Eight);
+    let mut writer = encoder.write_header()?;
+    writer.write_image_data(&pixels)?;
+    writer.finish()?;
+    Ok(data)
+}
+
+/// Draws the resistor into the file at `path`, choosing the image format from
+/// the file's extension. The `width` (in pixels) only applies to raster
+/// images.
+pub fn save(
+    group: &BandGroup,
+    body: Body,
+    caption: Option<&str>,
+    width: Option<u32>,
+    path: &Path,
+) -> Result<(), RenderError> {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    match ext.as_ref() {
+        "svg" => Ok(std::fs::write(path, svg(group, body, caption))?),
+        "png" => Ok(std::fs::write(
+            path,
+            png(group, body, caption, width.unwrap_or(WIDTH))?,
+        )?),
+        _ => Err(RenderError::UnsupportedFormat(ext)),
+    }
+}
+
+/// Width of a character of the caption font in pixels.
+const GLYPH_WIDTH: u32 = 5;
+
+/// Height of a character of the caption font in pixels.
+const GLYPH_HEIGHT: u32 = 7;
+
+/// Columns of the printable ASCII characters (starting from ' ') in a 5x7
+/// font, with the lowest bit as the top row.
+const FONT: [[u8; 5]; 95] = [
+    [0x00, 0x00, 0x00, 0x00, 0x00],
+    [0x00, 0x00, 0x5F, 0x00, 0x00],
+    [0x00, 0x07, 0x00, 0x07, 0x00],
+    [0x14, 0x7F, 0x14, 0x7F, 0x14],
+    [0x24, 0x2A, 0x7F, 0x2A, 0x12],
+    [0x23, 0x13, 0x08, 0x64, 0x62],
+    [0x36, 0x49, 0x55, 0x22, 0x50],
+    [0x00, 0x05, 0x03, 0x00, 0x00],
+    [0x00, 0x1C, 0x22, 0x41, 0x00],
+    [0x00, 0x41, 0x22, 0x1C, 0x00],
+    [0x14, 0x08, 0x3E, 0x08, 0x14],
+    [0x08, 0x08, 0x3E, 0x08, 0x08],
+    [0x00, 0x50, 0x30, 0x00, 0x00],
+    [0x08, 0x08, 0x08, 0x08, 0x08],
+    [0x00, 0x60, 0x60, 0x00, 0x00],
+    [0x20, 0x10, 0x08, 0x04, 0x02],
+    [0x3E, 0x51, 0x49, 0x45, 0x3E],
+    [0x00, 0x42, 0x7F, 0x40, 0x00],
+    [0x42, 0x61, 0x51, 0x49, 0x46],
+    [0x21, 0x41, 0x45, 0x4B, 0x31],
+    [0x18, 0x14, 0x12, 0x7F, 0x10],
+    [0x27, 0x45, 0x45, 0x45, 0x39],
+    [0x3C, 0x4A, 0x49, 0x49, 0x30],
+    [0x01, 0x71, 0x09, 0x05, 0x03],
+    [0x36, 0x49, 0x49, 0x49, 0x36],
+    [0x06, 0x49, 0x49, 0x29, 0x1E],
+    [0x00, 0x36, 0x36, 0x00, 0x00],
+    [0x00, 0x56, 0x36, 0x00, 0x00],
+    [0x08, 0x14, 0x22, 0x41, 0x00],
+    [0x14, 0x14, 0x14, 0x14, 0x14],
+    [0x00, 0x41, 0x22, 0x14, 0x08],
+    [0x02, 0x01, 0x51, 0x09, 0x06],
+    [0x32, 0x49, 0x79, 0x41, 0x3E],
+    [0x7E, 0x11, 0x11, 0x11, 0x7E],
+    [0x7F, 0x49, 0x49, 0x49, 0x36],
+    [0x3E, 0x41, 0x41, 0x41, 0x22],
+    [0x7F, 0x41, 0x41, 0x22, 0x1C],
+    [0x7F, 0x49, 0x49, 0x49, 0x41],
+    [0x7F, 0x09, 0x09, 0x01, 0x01],
+    [0x3E, 0x41, 0x41, 0x51, 0x32],
+    [0x7F, 0x08, 0x08, 0x08, 0x7F],
+    [0x00, 0x41, 0x7F, 0x41, 0x00],
+    [0x20, 0x40, 0x41, 0x3F, 0x01],
+    [0x7F, 0x08, 0x14, 0x22, 0x41],
+    [0x7F, 0x40, 0x40, 0x40, 0x40],
+    [0x7F, 0x02, 0x04, 0x02, 0x7F],
+    [0x7F, 0x04, 0x08, 0x10, 0x7F],
+    [0x3E, 0x41, 0x41, 0x41, 0x3E],
+    [0x7F, 0x09, 0x09, 0x09, 0x06],
+    [0x3E, 0x41, 0x51, 0x21, 0x5E],
+    [0x7F, 0x09, 0x19, 0x29, 0x46],
+    [0x46, 0x49, 0x49, 0x49, 0x31],
+    [0x01, 0x01, 0x7F, 0x01, 0x01],
+    [0x3F, 0x40, 0x40, 0x40, 0x3F],
+    [0x1F, 0x20, 0x40, 0x20, 0x1F],
+    [0x7F, 0x20, 0x18, 0x20, 0x7F],
+    [0x63, 0x14, 0x08, 0x14, 0x63],
+    [0x03, 0x04, 0x78, 0x04, 0x03],
+    [0x61, 0x51, 0x49, 0x45, 0x43],
+    [0x00, 0x7F, 0x41, 0x41, 0x00],
+    [0x02, 0x04, 0x08, 0x10, 0x20],
+    [0x00, 0x41, 0x41, 0x7F, 0x00],
+    [0x04, 0x02, 0x01, 0x02, 0x04],
+    [0x40, 0x40, 0x40, 0x40, 0x40],
+    [0x00, 0x01, 0x02, 0x04, 0x00],
+    [0x20, 0x54, 0x54, 0x54, 0x78],
+    [0x7F, 0x48, 0x44, 0x44, 0x38],
+    [0x38, 0x44, 0x44, 0x44, 0x20],
+    [0x38, 0x44, 0x44, 0x48, 0x7F],
+    [0x38, 0x54, 0x54, 0x54, 0x18],
+    [0x08, 0x7E, 0x09, 0x01, 0x02],
+    [0x0C, 0x52, 0x52, 0x52, 0x3E],
+    [0x7F, 0x08, 0x04, 0x04, 0x78],
+    [0x00, 0x44, 0x7D, 0x40, 0x00],
+    [0x20, 0x40, 0x44, 0x3D, 0x00],
+    [0x7F, 0x10, 0x28, 0x44, 0x00],
+    [0x00, 0x41, 0x7F, 0x40, 0x00],
+    [0x7C, 0x04, 0x18, 0x04, 0x78],
+    [0x7C, 0x08, 0x04, 0x04, 0x78],
+    [0x38, 0x44, 0x44, 0x44, 0x38],
+    [0x7C, 0x14, 0x14, 0x14, 0x08],
+    [0x08, 0x14, 0x14, 0x18, 0x7C],
+    [0x7C, 0x08, 0x04, 0x04, 0x08],
+    [0x48, 0x54, 0x54, 0x54, 0x20],
+    [0x04, 0x3F, 0x44, 0x40, 0x20],
+    [0x3C, 0x40, 0x40, 0x20, 0x7C],
+    [0x1C, 0x20, 0x40, 0x20, 0x1C],
+    [0x3C, 0x40, 0x30, 0x40, 0x3C],
+    [0x44, 0x28, 0x10, 0x28, 0x44],
+    [0x0C, 0x50, 0x50, 0x50, 0x3C],
+    [0x44, 0x64, 0x54, 0x4C, 0x44],
+    [0x00, 0x08, 0x36, 0x41, 0x00],
+    [0x00, 0x00, 0x7F, 0x00, 0x00],
+    [0x00, 0x41, 0x36, 0x08, 0x00],
+    [0x02, 0x01, 0x02, 0x04, 0x02],
+];
+
+/// Looks up the columns of the character `c` in the caption font, drawing a
+/// '?' for characters the font does not have.
+fn glyph(c: char) -> [u8; 5] {
+    match c {
+        'Ω' => [0x4E, 0x71, 0x01, 0x71, 0x4E],
+        '±' => [0x44, 0x44, 0x5F, 0x44, 0x44],
+        ' '..='~' => FONT[c as usize - ' ' as usize],
+        _ => FONT['?' as usize - ' ' as usize],
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum RenderError {
+    #[error("unsupported image format {0:?} (expects svg or png)")]
+    UnsupportedFormat(String),
+    #[error("image width must be between {MIN_PIXELS} and {MAX_PIXELS} pixels, not {0}")]
+    InvalidWidth(u32),
+    #[error("{0}")]
+    Png(#[from] ::png::EncodingError),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_band_spacing() {
+        let group = BandGroup::from(vec![Band::Brown, Band::Black, Band::Red, Band::Gold]);
+        let xs: Vec<f64> = bands(&group).iter().map(|(x, _, _)| *x).collect();
+        // the tolerance band is set apart by two empty slots
+        let step = xs[1] - xs[0];
+        assert!((xs[2] - xs[1] - step).abs() < 1e-9);
+        assert!((xs[3] - xs[2] - 3.0 * step).abs() < 1e-9);
+    }
+
+    #[test]
+    fn ut_svg_colors() {
+        let group = BandGroup::from(vec![Band::Brown, Band::Black, Band::Red, Band::Gold]);
+        let doc = svg(&group, Body::Carbon, Some("1 kΩ"));
+        assert!(doc.starts_with("<svg"));
+        assert!(doc.contains("fill=\"#D9C28F\""));
+        assert!(doc.contains("fill=\"#964B00\""));
+        assert!(doc.contains("fill=\"#FFD700\""));
+        assert!(doc.contains(">1 kΩ</text>"));
+        // the body takes the chosen color
+        let doc = svg(&group, Body::Metal, None);
+        assert!(doc.contains("fill=\"#7FB3E0\""));
+        assert!(!doc.contains("fill=\"#D9C28F\""));
+    }
+
+    #[test]
+    fn ut_png() {
+        let group = BandGroup::from(vec![Band::Brown, Band::Black, Band::Red, Band::Gold]);
+        let data = png(&group, Body::Carbon, None, 800).unwrap();
+        let decoder = ::png::Decoder::new(data.as_slice());
+        let reader = decoder.read_info().unwrap();
+        assert_eq!((reader.info().width, reader.info().height), (800, 240));
+        // a caption adds rows below the drawing
+        let data = png(&group, Body::Carbon, Some("1000.0 Ω ± 5.0%"), 800).unwrap();
+        let reader = ::png::Decoder::new(data.as_slice()).read_info().unwrap();
+        assert!(reader.info().height > 240);
+        assert!(png(&group, Body::Carbon, None, 10).is_err());
+        // the body takes the chosen color at the middle of a band-free slot
+        let data = png(&group, Body::Metal, None, 400).unwrap();
+        let mut reader = ::png::Decoder::new(data.as_slice()).read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        let p = ((HEIGHT / 2 * 400 + (SLOTS_LEFT + SLOTS_RIGHT) / 2 + 20) * 4) as usize;
+        assert!(pixels[p + 2] > pixels[p]);
+    }
+}
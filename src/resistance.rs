@@ -1,36 +1,101 @@
 use crate::ohm::Precision;
+use crate::value;
+use rust_decimal_macros::dec;
 use std::fmt::Display;
+use thiserror::Error;
 
-use crate::band::TempCoeff;
-
-#[derive(Debug, PartialEq)]
+/// A resistance in ohms with its tolerance in percent and its temperature
+/// coefficient in ppm/K.
+///
+/// With the `serde` feature, the value and tolerance are written as decimal
+/// strings (ex: `{"raw":"0.22","tol":"5","temp":null}`), which is also the
+/// shape of the object returned by the WebAssembly `decode`. This differs from
+/// [ohm_core::Resistance], which writes integer milliohms and ppm; convert
+/// between the two before reading the other's JSON.
+#[derive(Debug, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "Unchecked"))]
 pub struct Resistance {
     raw: Precision,
     tol: Precision,
-    temp: Option<TempCoeff>,
+    temp: Option<u8>,
 }
 
-impl Resistance {
-    pub fn new(raw: Precision, tol: Precision, temp: Option<TempCoeff>) -> Self {
-        Self {
-            raw: raw,
-            tol: tol,
-            temp: temp,
+/// The fields of a [Resistance] as read, before they are validated.
+#[cfg(feature = "serde")]
+#[derive(serde::Deserialize)]
+struct Unchecked {
+    raw: Precision,
+    tol: Precision,
+    temp: Option<u8>,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<Unchecked> for Resistance {
+    type Error = ResistanceError;
+
+    fn try_from(value: Unchecked) -> Result<Self, Self::Error> {
+        if value.raw.is_sign_negative() {
+            return Err(ResistanceError::NegativeValue(value.raw));
         }
+        if value.tol.is_sign_negative() {
+            return Err(ResistanceError::NegativeTolerance(value.tol));
+        }
+        Ok(Self::new(value.raw, value.tol, value.temp))
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+pub enum ResistanceError {
+    #[error("resistance {0} Ω is negative")]
+    NegativeValue(Precision),
+    #[error("tolerance {0}% is negative")]
+    NegativeTolerance(Precision),
+}
+
+impl Resistance {
+    pub fn new(raw: Precision, tol: Precision, temp: Option<u8>) -> Self {
+        Self { raw, tol, temp }
+    }
+
+    /// Returns the ideal value in Ohms.
+    pub fn raw(&self) -> Precision {
+        self.raw
+    }
+
+    /// Returns the allowed tolerance as a percentage.
+    pub fn tolerance(&self) -> Precision {
+        self.tol
     }
+
+    /// Returns the temperature coefficient in ppm/K, if marked.
+    pub fn temp_coeff(&self) -> Option<u8> {
+        self.temp
+    }
+
     /// Calculates the minimum-end of the allowed tolerance given the ideal `raw` value.
-    fn minimum(&self) -> Precision {
+    pub fn minimum(&self) -> Precision {
         self.raw - self.percent_error()
     }
 
     /// Calculates the maximum-end of the allowed tolerance given the ideal `raw` value.
-    fn maximum(&self) -> Precision {
+    pub fn maximum(&self) -> Precision {
         self.raw + self.percent_error()
     }
 
     /// Calculate the percent error allowed to devivate from the ideal value.
     fn percent_error(&self) -> Precision {
-        self.raw * (self.tol / 100.0)
+        self.raw * self.tol / dec!(100)
+    }
+}
+
+impl From<ohm_core::Resistance> for Resistance {
+    fn from(r: ohm_core::Resistance) -> Self {
+        Self::new(
+            value::ohms(r.raw()),
+            value::percent(r.tolerance()),
+            r.temp_coeff(),
+        )
     }
 }
 
@@ -38,18 +103,60 @@ impl Display for Resistance {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "{:?} Ω ± {:?}% (min: {:?} Ω, max: {:?} Ω){}",
-            self.raw,
-            self.tol,
-            self.minimum(),
-            self.maximum(),
-            {
-                if let Some(t) = self.temp {
-                    String::from(" ") + &t.to_string()
-                } else {
-                    String::new()
-                }
-            },
-        )
+            "{} Ω ± {}% (min: {} Ω, max: {} Ω)",
+            value::plain(self.raw),
+            value::plain(self.tol),
+            value::plain(self.minimum()),
+            value::plain(self.maximum()),
+        )?;
+        if let Some(t) = self.temp {
+            write!(f, " {} ppm/K", t)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ohm_core::{Milliohms, Ppm};
+
+    #[test]
+    fn ut_exact_tolerance() {
+        let r = Resistance::new(dec!(0.22), dec!(5), None);
+        assert_eq!(r.minimum(), dec!(0.209));
+        assert_eq!(r.maximum(), dec!(0.231));
+        assert_eq!(r.to_string(), "0.22 Ω ± 5.0% (min: 0.209 Ω, max: 0.231 Ω)");
+    }
+
+    #[test]
+    fn ut_from_core() {
+        let r = Resistance::from(ohm_core::Resistance::new(
+            Milliohms(220),
+            Ppm(500),
+            Some(50),
+        ));
+        assert_eq!(r, Resistance::new(dec!(0.22), dec!(0.05), Some(50)));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn ut_serde() {
+        let r = Resistance::new(dec!(0.22), dec!(5), None);
+        let json = serde_json::to_string(&r).unwrap();
+        assert_eq!(json, r#"{"raw":"0.22","tol":"5","temp":null}"#);
+        assert_eq!(serde_json::from_str::<Resistance>(&json).unwrap(), r);
+        let negative = r#"{"raw":"-0.22","tol":"5","temp":null}"#;
+        assert!(serde_json::from_str::<Resistance>(negative).is_err());
+        let negative = r#"{"raw":"0.22","tol":"-5","temp":null}"#;
+        assert!(serde_json::from_str::<Resistance>(negative).is_err());
+        // the core type writes the same resistance in integer units
+        let json = r#"{"raw":220,"tol":50000,"temp":15}"#;
+        let core: ohm_core::Resistance = serde_json::from_str(json).unwrap();
+        let r = Resistance::from(core.clone());
+        let text = serde_json::to_string(&r).unwrap();
+        assert_eq!(text, r#"{"raw":"0.22","tol":"5","temp":15}"#);
+        assert_eq!(serde_json::from_str::<Resistance>(&text).unwrap(), r);
+        assert_eq!(serde_json::to_string(&core).unwrap(), json);
     }
 }
@@ -0,0 +1,91 @@
+use crate::eseries::ESeries;
+use crate::ohm::Precision;
+use crate::value::{self, significand};
+use rust_decimal_macros::dec;
+use thiserror::Error;
+
+/// Multiplier letters of the EIA-96 marking code, starting from 10^-3.
+const EIA96_LETTERS: [char; 9] = ['Z', 'Y', 'X', 'A', 'B', 'C', 'D', 'E', 'F'];
+
+/// Writes `value` using `digits` significant digits, where a value too small
+/// for a multiplier digit uses `R` as the decimal point.
+fn digit_code(value: Precision, digits: u32) -> Option<String> {
+    let (sig, exp) = significand(value, digits)?;
+    let sig = format!("{:0width$}", sig, width = digits as usize);
+    match exp {
+        0..=9 => Some(format!("{}{}", sig, exp)),
+        _ => {
+            // place the 'R' where the decimal point belongs
+            let point = digits as i32 + exp;
+            match point {
+                0 => Some(format!("R{}", sig)),
+                p if p > 0 && p < digits as i32 => {
+                    let (whole, frac) = sig.split_at(p as usize);
+                    Some(format!("{}R{}", whole, frac))
+                }
+                _ => None,
+            }
+        }
+    }
+}
+
+/// Writes `value` using the EIA-96 code, which requires an E96 value.
+fn eia96_code(value: Precision) -> Option<String> {
+    let (sig, exp) = significand(value, 3)?;
+    let index = ESeries::E96
+        .significands()
+        .iter()
+        .position(|s| *s as u32 == sig)?;
+    let letter = EIA96_LETTERS.get(usize::try_from(exp + 3).ok()?)?;
+    Some(format!("{:02}{}", index + 1, letter))
+}
+
+/// Writes the marking printed on a surface-mount resistor of `value` Ohms.
+///
+/// Precision parts use the EIA-96 code when possible, or else the 4-digit
+/// code; all other parts use the 3-digit code.
+pub fn encode(value: Precision, tolerance: Precision) -> Result<String, SmdError> {
+    let code = if tolerance <= dec!(1) {
+        eia96_code(value).or_else(|| digit_code(value, 3))
+    } else if tolerance <= dec!(2) {
+        digit_code(value, 3)
+    } else {
+        digit_code(value, 2).or_else(|| digit_code(value, 3))
+    };
+    code.ok_or(SmdError::Unrepresentable(value))
+}
+
+#[derive(Error, Debug)]
+pub enum SmdError {
+    #[error("{} Ω cannot be represented with an SMD code", value::plain(*.0))]
+    Unrepresentable(Precision),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_3_digit_code() {
+        assert_eq!(encode(dec!(4700), dec!(5)).unwrap(), "472");
+        assert_eq!(encode(dec!(10), dec!(5)).unwrap(), "100");
+        assert_eq!(encode(dec!(4.7), dec!(5)).unwrap(), "4R7");
+        assert_eq!(encode(dec!(0.47), dec!(5)).unwrap(), "R47");
+    }
+
+    #[test]
+    fn ut_4_digit_code() {
+        assert_eq!(encode(dec!(4750), dec!(2)).unwrap(), "4751");
+        assert_eq!(encode(dec!(47.5), dec!(2)).unwrap(), "47R5");
+        // a 5% part that needs a third digit
+        assert_eq!(encode(dec!(4750), dec!(5)).unwrap(), "4751");
+    }
+
+    #[test]
+    fn ut_eia96_code() {
+        assert_eq!(encode(dec!(10_000), dec!(1)).unwrap(), "01C");
+        assert_eq!(encode(dec!(4750), dec!(1)).unwrap(), "66B");
+        // not an E96 value
+        assert_eq!(encode(dec!(4700), dec!(1)).unwrap(), "4701");
+    }
+}
@@ -0,0 +1,237 @@
+use crate::color::Rgb;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+/// Colors of the 16 standard ANSI colors (as drawn by xterm), in order of
+/// their color numbers.
+const ANSI_16: [(u8, u8, u8); 16] = [
+    (0x00, 0x00, 0x00),
+    (0xCD, 0x00, 0x00),
+    (0x00, 0xCD, 0x00),
+    (0xCD, 0xCD, 0x00),
+    (0x00, 0x00, 0xEE),
+    (0xCD, 0x00, 0xCD),
+    (0x00, 0xCD, 0xCD),
+    (0xE5, 0xE5, 0xE5),
+    (0x7F, 0x7F, 0x7F),
+    (0xFF, 0x00, 0x00),
+    (0x00, 0xFF, 0x00),
+    (0xFF, 0xFF, 0x00),
+    (0x5C, 0x5C, 0xFF),
+    (0xFF, 0x00, 0xFF),
+    (0x00, 0xFF, 0xFF),
+    (0xFF, 0xFF, 0xFF),
+];
+
+/// Intensities of each component of the 6x6x6 color cube in the 256-color
+/// palette.
+const CUBE_LEVELS: [u8; 6] = [0x00, 0x5F, 0x87, 0xAF, 0xD7, 0xFF];
+
+/// The range of colors a terminal can display.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum ColorSupport {
+    None,
+    Ansi16,
+    Ansi256,
+    TrueColor,
+}
+
+impl ColorSupport {
+    /// Determines the colors available from the environment variables read by
+    /// `var`, where `tty` is whether the output goes to a terminal.
+    fn from_env(var: impl Fn(&str) -> Option<String>, tty: bool) -> Self {
+        // see https://no-color.org
+        if var("NO_COLOR").is_some_and(|v| !v.is_empty()) || !tty {
+            return Self::None;
+        }
+        if let Some("truecolor" | "24bit") = var("COLORTERM").as_deref() {
+            return Self::TrueColor;
+        }
+        match var("TERM") {
+            Some(t) if t.is_empty() || t == "dumb" => Self::None,
+            Some(t) if t.contains("truecolor") || t.contains("direct") => Self::TrueColor,
+            Some(t) if t.contains("256color") => Self::Ansi256,
+            Some(_) => Self::Ansi16,
+            // modern Windows consoles do not set TERM but draw any color
+            None if cfg!(windows) => Self::TrueColor,
+            None => Self::None,
+        }
+    }
+}
+
+impl FromStr for ColorSupport {
+    type Err = TermError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_ref() {
+            "never" | "none" => Ok(Self::None),
+            "16" => Ok(Self::Ansi16),
+            "256" => Ok(Self::Ansi256),
+            "truecolor" | "24bit" => Ok(Self::TrueColor),
+            _ => Err(TermError::UnknownColors(s.to_string())),
+        }
+    }
+}
+
+/// What the terminal receiving the output is able to display.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Terminal {
+    pub colors: ColorSupport,
+}
+
+impl Terminal {
+    /// Determines the capabilities of the terminal connected to stdout.
+    pub fn detect() -> Self {
+        Self::from_env(|k| std::env::var(k).ok(), std::io::stdout().is_terminal())
+    }
+
+    /// Returns the capabilities of the terminal connected to stdout, which
+    /// are only detected once.
+    pub fn current() -> &'static Self {
+        static CURRENT: OnceLock<Terminal> = OnceLock::new();
+        CURRENT.get_or_init(Self::detect)
+    }
+
+    fn from_env(var: impl Fn(&str) -> Option<String>, tty: bool) -> Self {
+        Self {
+            colors: ColorSupport::from_env(var, tty),
+        }
+    }
+
+    /// Colors the `text` with the closest color to `rgb` that the terminal
+    /// can display.
+    pub fn paint(&self, text: &str, rgb: (u8, u8, u8)) -> String {
+        match self.code(rgb, false) {
+            Some(code) => format!("\x1b[{}m{}\x1b[0m", code, text),
+            None => text.to_string(),
+        }
+    }
+
+    /// Colors the background behind `text` with the closest color to `rgb`
+    /// that the terminal can display, keeping it behind any text within
+    /// `text` that is colored on its own.
+    pub fn fill(&self, text: &str, rgb: (u8, u8, u8)) -> String {
+        match self.code(rgb, true) {
+            Some(code) => {
+                let start = format!("\x1b[{}m", code);
+                let text = text.replace("\x1b[0m", &format!("\x1b[0m{}", start));
+                format!("{}{}\x1b[0m", start, text)
+            }
+            None => text.to_string(),
+        }
+    }
+
+    /// Writes the parameters of the SGR escape sequence that sets the
+    /// foreground (or `background`) to the color closest to `rgb`.
+    fn code(&self, rgb: (u8, u8, u8), background: bool) -> Option<String> {
+        let layer = match background {
+            true => 10,
+            false => 0,
+        };
+        match self.colors {
+            ColorSupport::None => None,
+            ColorSupport::TrueColor => {
+                Some(format!("{};2;{};{};{}", 38 + layer, rgb.0, rgb.1, rgb.2))
+            }
+            ColorSupport::Ansi256 => Some(format!("{};5;{}", 38 + layer, nearest_256(rgb))),
+            ColorSupport::Ansi16 => Some(match nearest_16(rgb) {
+                n if n < 8 => (30 + layer + n).to_string(),
+                n => (90 + layer + n - 8).to_string(),
+            }),
+        }
+    }
+}
+
+/// Finds the index of the color in `palette` that looks closest to `rgb`.
+fn nearest(rgb: (u8, u8, u8), palette: impl Iterator<Item = (u8, u8, u8)>) -> usize {
+    let lab = Rgb::from(rgb).lab();
+    palette
+        .map(|c| lab.delta_e(&Rgb::from(c).lab()))
+        .enumerate()
+        .min_by(|(_, x), (_, y)| x.total_cmp(y))
+        .map_or(0, |(i, _)| i)
+}
+
+/// Finds the closest of the 16 standard ANSI colors to `rgb`.
+fn nearest_16(rgb: (u8, u8, u8)) -> u8 {
+    nearest(rgb, ANSI_16.into_iter()) as u8
+}
+
+/// Finds the closest color to `rgb` in the 256-color palette, skipping the
+/// first 16 colors since terminal themes often change them.
+fn nearest_256(rgb: (u8, u8, u8)) -> u8 {
+    let cube = (0..216).map(|i| {
+        (
+            CUBE_LEVELS[i / 36],
+            CUBE_LEVELS[i / 6 % 6],
+            CUBE_LEVELS[i % 6],
+        )
+    });
+    let grays = (0..24).map(|i| {
+        let v = 8 + 10 * i as u8;
+        (v, v, v)
+    });
+    16 + nearest(rgb, cube.chain(grays)) as u8
+}
+
+#[derive(Error, Debug)]
+pub enum TermError {
+    #[error("unknown color mode {0:?} (expects never, 16, 256, or truecolor)")]
+    UnknownColors(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn env<'a>(vars: &'a [(&'a str, &'a str)]) -> impl Fn(&str) -> Option<String> + 'a {
+        move |k| {
+            vars.iter()
+                .find(|(name, _)| *name == k)
+                .map(|(_, v)| v.to_string())
+        }
+    }
+
+    #[test]
+    fn ut_detect_colors() {
+        let detect = |vars: &[(&str, &str)], tty| ColorSupport::from_env(env(vars), tty);
+        assert_eq!(
+            detect(
+                &[("TERM", "xterm-256color"), ("COLORTERM", "truecolor")],
+                true
+            ),
+            ColorSupport::TrueColor
+        );
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color")], true),
+            ColorSupport::Ansi256
+        );
+        assert_eq!(detect(&[("TERM", "xterm")], true), ColorSupport::Ansi16);
+        assert_eq!(detect(&[("TERM", "dumb")], true), ColorSupport::None);
+        assert_eq!(
+            detect(&[("TERM", "xterm-256color"), ("NO_COLOR", "1")], true),
+            ColorSupport::None
+        );
+        // output redirected to a file
+        assert_eq!(detect(&[("TERM", "xterm")], false), ColorSupport::None);
+    }
+
+    #[test]
+    fn ut_nearest_palette_color() {
+        assert_eq!(nearest_256((0xFF, 0x00, 0x00)), 196);
+        assert_eq!(nearest_256((0x80, 0x80, 0x80)), 244);
+        // gold is shown as the darker of the two yellows
+        assert_eq!(nearest_16((0xFF, 0xD7, 0x00)), 3);
+        let term = Terminal {
+            colors: ColorSupport::Ansi16,
+        };
+        assert_eq!(term.paint("x", (0x00, 0x00, 0x00)), "\x1b[30mx\x1b[0m");
+        // colored text stays on the background
+        assert_eq!(
+            term.fill(&term.paint("x", (0x00, 0x00, 0x00)), (0xFF, 0xFF, 0xFF)),
+            "\x1b[107m\x1b[30mx\x1b[0m\x1b[107m\x1b[0m"
+        );
+    }
+}
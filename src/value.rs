@@ -0,0 +1,285 @@
+use crate::ohm::Precision;
+use ohm_core::{Milliohms, Ppm};
+use rust_decimal_macros::dec;
+use std::fmt::Display;
+use std::str::FromStr;
+use thiserror::Error;
+
+/// A resistance written by a user, such as `4k7`, `4.7k`, `2R2`, or `1013`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Value(Precision);
+
+impl Value {
+    pub fn new(ohms: Precision) -> Self {
+        Self(ohms)
+    }
+
+    pub fn ohms(&self) -> Precision {
+        self.0
+    }
+}
+
+/// Maps a metric prefix (or RKM code letter) to its multiplier.
+fn prefix(c: char) -> Option<Precision> {
+    match c {
+        'L' | 'm' => Some(dec!(0.001)),
+        'R' | 'r' => Some(dec!(1)),
+        'k' | 'K' => Some(dec!(1_000)),
+        'M' => Some(dec!(1_000_000)),
+        'G' => Some(dec!(1_000_000_000)),
+        _ => None,
+    }
+}
+
+/// Computes `sig` x 10^`exp` exactly.
+pub fn scale(sig: u32, exp: i32) -> Precision {
+    match exp >= 0 {
+        true => Precision::from(sig) * Precision::from(10_u64.pow(exp as u32)),
+        false => Precision::new(sig.into(), exp.unsigned_abs()),
+    }
+}
+
+/// Converts a value decoded from the bands to Ohms.
+pub fn ohms(value: Milliohms) -> Precision {
+    Precision::new(value.0 as i64, 3).normalize()
+}
+
+/// Converts a tolerance decoded from the bands to a percentage.
+pub fn percent(value: Ppm) -> Precision {
+    Precision::new(value.0.into(), 4).normalize()
+}
+
+/// Converts a percentage to parts per million, if it is a whole number of
+/// them.
+pub fn ppm(percent: Precision) -> Option<Ppm> {
+    let ppm = percent * dec!(10_000);
+    match ppm.fract().is_zero() {
+        true => u32::try_from(ppm.mantissa() / 10_i128.pow(ppm.scale()))
+            .ok()
+            .map(Ppm),
+        false => None,
+    }
+}
+
+/// Splits `value` into a significand of `digits` digits and a power of ten,
+/// if it can be written exactly with that many significant digits.
+pub fn significand(value: Precision, digits: u32) -> Option<(u32, i32)> {
+    if value <= Precision::ZERO {
+        return None;
+    }
+    let value = value.normalize();
+    let mut sig = value.mantissa();
+    let mut exp = -(value.scale() as i32);
+    while sig % 10 == 0 {
+        sig /= 10;
+        exp += 1;
+    }
+    let pad = digits.checked_sub(sig.ilog10() + 1)?;
+    Some(((sig * 10_i128.pow(pad)) as u32, exp - pad as i32))
+}
+
+/// Writes `value` in full with at least one decimal place (ex: `1000.0` or
+/// `0.22`).
+pub fn plain(value: Precision) -> String {
+    let value = value.normalize();
+    match value.scale() {
+        0 => format!("{}.0", value),
+        _ => value.to_string(),
+    }
+}
+
+impl FromStr for Value {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let err = || ValueError::Invalid(s.to_string());
+        // remove any spacing and the unit from the end
+        let text: String = s.chars().filter(|c| !c.is_whitespace()).collect();
+        let text = text
+            .strip_suffix("ohms")
+            .or_else(|| text.strip_suffix("ohm"))
+            .or_else(|| text.strip_suffix('Ω'))
+            .unwrap_or(&text);
+        // scientific notation (ex: 4.7e3) takes the place of a prefix letter
+        if let Ok(n) = Precision::from_scientific(text) {
+            return match n > Precision::ZERO {
+                true => Ok(Self(n)),
+                false => Err(err()),
+            };
+        }
+        // locate the (optional) prefix letter
+        let (number, mult) = match text.char_indices().find(|(_, c)| c.is_ascii_alphabetic()) {
+            Some((i, c)) => {
+                let mult = prefix(c).ok_or_else(err)?;
+                let (whole, frac) = (&text[..i], &text[i + c.len_utf8()..]);
+                match frac.is_empty() {
+                    // the letter is a suffix (ex: 4.7k)
+                    true => (whole.to_string(), mult),
+                    // the letter replaces the decimal point (ex: 4k7)
+                    false => match whole.contains('.') || frac.contains('.') {
+                        true => return Err(err()),
+                        false => (format!("{}.{}", whole, frac), mult),
+                    },
+                }
+            }
+            None => (text.to_string(), dec!(1)),
+        };
+        match number.parse::<Precision>() {
+            Ok(n) if n > Precision::ZERO => n.checked_mul(mult).map(Self).ok_or_else(err),
+            _ => Err(err()),
+        }
+    }
+}
+
+impl Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let (scaled, unit) = match self.0 {
+            v if v >= dec!(1e9) => (v / dec!(1e9), "GΩ"),
+            v if v >= dec!(1e6) => (v / dec!(1e6), "MΩ"),
+            v if v >= dec!(1e3) => (v / dec!(1e3), "kΩ"),
+            v => (v, "Ω"),
+        };
+        write!(f, "{} {}", scaled.round_dp(3).normalize(), unit)
+    }
+}
+
+/// A percentage written by a user, such as `1%` or `0.5`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Percent(Precision);
+
+impl Percent {
+    pub fn value(&self) -> Precision {
+        self.0
+    }
+}
+
+impl FromStr for Percent {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        match text
+            .strip_suffix('%')
+            .unwrap_or(text)
+            .trim()
+            .parse::<Precision>()
+        {
+            Ok(n) if n >= Precision::ZERO => Ok(Self(n.normalize())),
+            _ => Err(ValueError::InvalidPercent(s.to_string())),
+        }
+    }
+}
+
+impl Display for Percent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+/// A span of resistances written by a user, such as `1k..10k`, `..470`, or
+/// `1M..`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct ValueRange {
+    lo: Option<Value>,
+    hi: Option<Value>,
+}
+
+impl ValueRange {
+    /// Checks if `ohms` falls within the range (inclusive).
+    pub fn contains(&self, ohms: Precision) -> bool {
+        self.lo.is_none_or(|lo| ohms >= lo.ohms()) && self.hi.is_none_or(|hi| ohms <= hi.ohms())
+    }
+}
+
+impl FromStr for ValueRange {
+    type Err = ValueError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let bound = |t: &str| match t.trim().is_empty() {
+            true => Ok(None),
+            false => Value::from_str(t).map(Some),
+        };
+        match s.split_once("..") {
+            Some((lo, hi)) => Ok(Self {
+                lo: bound(lo)?,
+                hi: bound(hi)?,
+            }),
+            None => Err(ValueError::InvalidRange(s.to_string())),
+        }
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let bound = |v: Option<Value>| v.map_or(String::new(), |v| v.to_string());
+        write!(f, "{}..{}", bound(self.lo), bound(self.hi))
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum ValueError {
+    #[error("invalid resistance value {0:?}")]
+    Invalid(String),
+    #[error("invalid percentage {0:?}")]
+    InvalidPercent(String),
+    #[error("invalid range {0:?} (expects <low>..<high>)")]
+    InvalidRange(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ut_parse_value() {
+        assert_eq!(Value::from_str("1013").unwrap().ohms(), dec!(1013));
+        assert_eq!(Value::from_str("4k7").unwrap().ohms(), dec!(4700));
+        assert_eq!(Value::from_str("4.7k").unwrap().ohms(), dec!(4700));
+        assert_eq!(Value::from_str("4.68 kΩ").unwrap().ohms(), dec!(4680));
+        assert_eq!(Value::from_str("2R2").unwrap().ohms(), dec!(2.2));
+        assert_eq!(Value::from_str("1M").unwrap().ohms(), dec!(1e6));
+        assert_eq!(Value::from_str("0.1k").unwrap().ohms(), dec!(100));
+        assert!(Value::from_str("4k7.1").is_err());
+        assert!(Value::from_str("4x7").is_err());
+        assert!(Value::from_str("-10").is_err());
+        assert_eq!(Value::from_str("1e3").unwrap().ohms(), dec!(1000));
+        assert_eq!(Value::from_str("4.7e3").unwrap().ohms(), dec!(4700));
+        assert_eq!(Value::from_str("2.2E-1 ohms").unwrap().ohms(), dec!(0.22));
+        assert!(Value::from_str("-1e3").is_err());
+        assert!(Value::from_str("1e3k").is_err());
+    }
+
+    #[test]
+    fn ut_display_value() {
+        assert_eq!(Value::new(dec!(4700)).to_string(), "4.7 kΩ");
+        assert_eq!(Value::new(dec!(1e6)).to_string(), "1 MΩ");
+        assert_eq!(Value::new(dec!(0.22)).to_string(), "0.22 Ω");
+    }
+
+    #[test]
+    fn ut_parse_range() {
+        let r = ValueRange::from_str("1k..10k").unwrap();
+        assert!(r.contains(dec!(4700)));
+        assert!(!r.contains(dec!(47_000)));
+        assert!(ValueRange::from_str("..").unwrap().contains(dec!(1e9)));
+        assert!(ValueRange::from_str("1k").is_err());
+    }
+
+    #[test]
+    fn ut_parse_percent() {
+        assert_eq!(Percent::from_str("1%").unwrap().value(), dec!(1));
+        assert_eq!(Percent::from_str("0.5").unwrap().value(), dec!(0.5));
+        assert!(Percent::from_str("five").is_err());
+    }
+
+    #[test]
+    fn ut_exact_decimals() {
+        assert_eq!(scale(22, -2), dec!(0.22));
+        assert_eq!(scale(47, 3), dec!(47_000));
+        assert_eq!(significand(dec!(4700), 2), Some((47, 2)));
+        assert_eq!(significand(dec!(0.47), 3), Some((470, -3)));
+        assert_eq!(significand(dec!(4750), 2), None);
+        assert_eq!(plain(dec!(1000)), "1000.0");
+        assert_eq!(plain(scale(22, -2)), "0.22");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::band::{self, Band, Tolerance};
+use crate::resistance::Resistance;
+use crate::value::{Percent, Value};
+use ohm_core::{Resistor, Standard};
+use serde::Serialize;
+use wasm_bindgen::prelude::*;
+
+/// Looks up the color code tables named `standard` (ex: `iec60062-2016`),
+/// using the classic tables when it is not given.
+fn standard(standard: Option<String>) -> Result<Standard, JsError> {
+    Ok(standard.map_or(Ok(Standard::default()), |s| s.parse())?)
+}
+
+/// Reads the bands, listed from left to right, as an object with the `raw`,
+/// `tol`, and `temp` fields of a resistance under the color code `standard`
+/// when given. The object has the same shape as the JSON of a [Resistance].
+#[wasm_bindgen]
+pub fn decode(bands: Vec<String>, standard: Option<String>) -> Result<JsValue, JsError> {
+    let standard = self::standard(standard)?;
+    let bands = bands
+        .iter()
+        .map(|s| band::parse(s))
+        .collect::<Result<Vec<Band>, _>>()?;
+    let resistance = Resistance::from(Resistor::decode(&bands)?.resistance_with(&standard)?);
+    // a missing temperature coefficient is null, as in JSON, not undefined
+    Ok(resistance.serialize(&serde_wasm_bindgen::Serializer::json_compatible())?)
+}
+
+/// Writes a resistance (ex: `4k7`) as the names of its bands, ending with the
+/// band for the `tolerance` (ex: `5%`) under the color code `standard` when
+/// given.
+#[wasm_bindgen]
+pub fn encode(
+    value: &str,
+    tolerance: Option<String>,
+    standard: Option<String>,
+) -> Result<Vec<String>, JsError> {
+    let standard = self::standard(standard)?;
+    let value: Value = value.parse()?;
+    let tolerance = match tolerance {
+        Some(t) => band::tolerance(t.parse::<Percent>()?.value(), &standard)?,
+        None => Tolerance::Default,
+    };
+    // prefer the shorter code
+    let bands = band::encode(value.ohms(), 2, tolerance)
+        .or_else(|_| band::encode(value.ohms(), 3, tolerance))?;
+    Ok(bands.iter().map(|b| b.ascii().to_string()).collect())
+}
+
+/// Writes a resistance given by [decode] as the command-line tool prints it.
+#[wasm_bindgen]
+pub fn format(resistance: JsValue) -> Result<String, JsError> {
+    let resistance: Resistance = serde_wasm_bindgen::from_value(resistance)?;
+    Ok(resistance.to_string())
+}
+
+#[cfg(all(test, target_arch = "wasm32"))]
+mod tests {
+    use super::*;
+    use rust_decimal_macros::dec;
+    use wasm_bindgen_test::*;
+
+    fn names(bands: &[&str]) -> Vec<String> {
+        bands.iter().map(|b| b.to_string()).collect()
+    }
+
+    #[wasm_bindgen_test]
+    fn ut_decode() {
+        let r = decode(names(&["yellow", "violet", "red", "gold"]), None).unwrap();
+        assert_eq!(
+            serde_wasm_bindgen::from_value::<Resistance>(r.clone()).unwrap(),
+            Resistance::new(dec!(4700), dec!(5), None)
+        );
+        assert_eq!(
+            format(r).unwrap(),
+            "4700.0 Ω ± 5.0% (min: 4465.0 Ω, max: 4935.0 Ω)"
+        );
+        assert!(decode(names(&["red", "red"]), None).is_err());
+        let r = decode(
+            names(&["brown", "black", "black", "red", "orange"]),
+            Some(String::from("iec60062-2016")),
+        )
+        .unwrap();
+        assert_eq!(
+            serde_wasm_bindgen::from_value::<Resistance>(r).unwrap(),
+            Resistance::new(dec!(10_000), dec!(0.05), None)
+        );
+    }
+
+    #[wasm_bindgen_test]
+    fn ut_encode() {
+        assert_eq!(
+            encode("4k7", Some(String::from("5%")), None).unwrap(),
+            names(&["yellow", "violet", "red", "gold"])
+        );
+        assert_eq!(
+            encode("0.47", None, None).unwrap(),
+            names(&["yellow", "violet", "silver"])
+        );
+        assert!(encode("4k75", Some(String::from("7%")), None).is_err());
+        assert_eq!(
+            encode(
+                "10k",
+                Some(String::from("0.05%")),
+                Some(String::from("iec60062-2016"))
+            )
+            .unwrap(),
+            names(&["brown", "black", "orange", "orange"])
+        );
+    }
+}
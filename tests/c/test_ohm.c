@@ -0,0 +1,75 @@
+/* Tests of the C API, linked against the static library. */
+#include <stdio.h>
+#include <string.h>
+
+#include "ohm.h"
+
+static int failures = 0;
+
+#define CHECK(cond)                                                    \
+  do {                                                                 \
+    if (!(cond)) {                                                     \
+      fprintf(stderr, "%s:%d: check failed: %s\n", __FILE__, __LINE__, \
+              #cond);                                                  \
+      failures++;                                                      \
+    }                                                                  \
+  } while (0)
+
+static void test_decode_bands(void) {
+  OhmResistance r;
+  uint32_t bands[] = {OHM_BAND_YELLOW, OHM_BAND_VIOLET, OHM_BAND_RED,
+                      OHM_BAND_GOLD};
+  CHECK(ohm_decode_bands(bands, 4, &r) == OHM_STATUS_OK);
+  CHECK(r.nominal == 4700.0);
+  CHECK(r.min == 4465.0);
+  CHECK(r.max == 4935.0);
+  CHECK(r.tolerance == 5.0);
+  CHECK(r.tempco == 0);
+  CHECK(!r.has_tempco);
+
+  uint32_t six[] = {OHM_BAND_RED,   OHM_BAND_VIOLET, OHM_BAND_YELLOW,
+                    OHM_BAND_BLACK, OHM_BAND_RED,    OHM_BAND_BROWN};
+  CHECK(ohm_decode_bands(six, 6, &r) == OHM_STATUS_OK);
+  CHECK(r.nominal == 274.0);
+  CHECK(r.tempco == 100);
+  CHECK(r.has_tempco);
+}
+
+static void test_decode_errors(void) {
+  OhmResistance r;
+  uint32_t reversed[] = {OHM_BAND_GOLD, OHM_BAND_RED, OHM_BAND_VIOLET,
+                         OHM_BAND_YELLOW};
+  CHECK(ohm_decode_bands(reversed, 4, &r) == OHM_STATUS_REVERSED_BAND_ORDER);
+  CHECK(ohm_decode_bands(reversed, 2, &r) == OHM_STATUS_OUT_OF_RANGE);
+  uint32_t gold[] = {OHM_BAND_RED, OHM_BAND_GOLD, OHM_BAND_RED, OHM_BAND_GOLD};
+  CHECK(ohm_decode_bands(gold, 4, &r) == OHM_STATUS_UNSUPPORTED_BAND);
+  uint32_t invalid[] = {OHM_BAND_RED, 13, OHM_BAND_RED, OHM_BAND_GOLD};
+  CHECK(ohm_decode_bands(invalid, 4, &r) == OHM_STATUS_INVALID_BAND);
+  uint32_t negative[] = {OHM_BAND_RED, (uint32_t)-1, OHM_BAND_RED, OHM_BAND_GOLD};
+  CHECK(ohm_decode_bands(negative, 4, &r) == OHM_STATUS_INVALID_BAND);
+  CHECK(ohm_decode_bands(NULL, 4, &r) == OHM_STATUS_NULL_POINTER);
+  CHECK(strcmp(ohm_status_message(OHM_STATUS_OK), "ok") == 0);
+}
+
+static void test_decode_value(void) {
+  OhmResistance r;
+  CHECK(ohm_decode_value("4k7", "1%", &r) == OHM_STATUS_OK);
+  CHECK(r.nominal == 4700.0);
+  CHECK(r.tolerance == 1.0);
+  CHECK(ohm_decode_value("2R2", NULL, &r) == OHM_STATUS_OK);
+  CHECK(r.nominal == 2.2);
+  CHECK(r.tolerance == 5.0);
+  CHECK(ohm_decode_value("2Q2", NULL, &r) == OHM_STATUS_INVALID_VALUE);
+  CHECK(ohm_decode_value("4k7", "loose", &r) == OHM_STATUS_INVALID_VALUE);
+}
+
+int main(void) {
+  test_decode_bands();
+  test_decode_errors();
+  test_decode_value();
+  if (failures > 0) {
+    fprintf(stderr, "%d check(s) failed\n", failures);
+    return 1;
+  }
+  return 0;
+}